@@ -0,0 +1,61 @@
+#[path = "../../common/puzzles.rs"]
+mod puzzles;
+
+use puzzles::{Puzzle, PUZZLES};
+
+fn get_puzzle(day: &str) -> &'static Puzzle {
+    let day = day.parse::<u32>().unwrap_or_else(|_| panic!("Invalid day {:?}", day));
+    PUZZLES
+        .iter()
+        .find(|puzzle| puzzle.day == day)
+        .unwrap_or_else(|| panic!("There is no puzzle for day {:}", day))
+}
+
+// day 25 only has one puzzle, its second star comes for free
+fn get_solution_path(puzzle: &Puzzle, part: usize) -> String {
+    let file = if puzzle.day == 25 && part == 2 { "README.md" } else { "src/main.rs" };
+    std::format!("day{:02}_part{:}/{:}", puzzle.day, part, file)
+}
+
+fn print_info(puzzle: &Puzzle) {
+    println!("Day {:}: {:}", puzzle.day, puzzle.title);
+    println!("https://adventofcode.com/2021/day/{:}", puzzle.day);
+    println!("input:  {:}", puzzle.input_grammar);
+    for (idx, part) in puzzle.parts.iter().enumerate() {
+        println!("part {:}: {:} ({:})", idx + 1, part, get_solution_path(puzzle, idx + 1));
+    }
+}
+
+// the puzzle table in the README, pipes inside cells are escaped
+fn print_readme_table() {
+    let escape = |val: &str| val.replace('|', "\\|");
+    println!("| Day | Title | Input | Part 1 | Part 2 | Solutions |");
+    println!("| --- | --- | --- | --- | --- | --- |");
+    for puzzle in &PUZZLES {
+        println!(
+            "| {:} | [{:}](https://adventofcode.com/2021/day/{:}) | {:} | {:} | {:} | [part 1]({:}), [part 2]({:}) |",
+            puzzle.day,
+            puzzle.title,
+            puzzle.day,
+            escape(puzzle.input_grammar),
+            escape(puzzle.parts[0]),
+            escape(puzzle.parts[1]),
+            get_solution_path(puzzle, 1),
+            get_solution_path(puzzle, 2),
+        );
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    match args.get(1).map(String::as_str) {
+        Some("list") | None => {
+            for puzzle in &PUZZLES {
+                println!("{:>2}  {:}", puzzle.day, puzzle.title);
+            }
+        },
+        Some("info") => print_info(get_puzzle(args.get(2).expect("info expects a day"))),
+        Some("readme") => print_readme_table(),
+        Some(cmd) => panic!("Unknown command {:?}, expected list, info DAY or readme", cmd),
+    }
+}
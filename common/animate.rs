@@ -0,0 +1,84 @@
+use std::cell::Cell;
+use std::io::BufRead;
+use std::io::Write;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::Duration;
+
+const DEFAULT_FPS: u64 = 10;
+
+// Plays frames in the terminal using plain ANSI escapes.
+// While playing, <enter> pauses & "q" <enter> stops drawing.
+// While paused (or in step mode) every frame waits for input:
+//   <enter> shows the next frame, "p" resumes playback, "q" stops drawing.
+pub struct Animation {
+    frame_time: Duration,
+    step: Cell<bool>,
+    stopped: Cell<bool>,
+    // stdin lines, read on a separate thread so playback can check for them without blocking
+    keys: Receiver<String>,
+}
+
+impl Animation {
+    // usage: --animate [fps] [--step]
+    pub fn from_args(args: &[String]) -> Option<Animation> {
+        let pos = args.iter().position(|arg| arg == "--animate")?;
+        let fps = args
+            .get(pos + 1)
+            .and_then(|val| val.parse::<u64>().ok())
+            .unwrap_or(DEFAULT_FPS)
+            .max(1);
+
+        let (sender, keys) = mpsc::channel();
+        thread::spawn(move || {
+            for line in std::io::stdin().lock().lines() {
+                let line = match line {
+                    Ok(line) => line,
+                    Err(_) => return,
+                };
+                if sender.send(line.trim().to_string()).is_err() {
+                    return;
+                }
+            }
+        });
+
+        Some(Animation {
+            frame_time: Duration::from_millis(1000 / fps),
+            step: Cell::new(args.iter().any(|arg| arg == "--step")),
+            stopped: Cell::new(false),
+            keys,
+        })
+    }
+
+    pub fn show(&self, title: &str, frame: &str) {
+        if self.stopped.get() {
+            return;
+        }
+
+        let mut stdout = std::io::stdout().lock();
+        write!(stdout, "\x1b[2J\x1b[H{:}\n\n{:}", title, frame).unwrap();
+        if self.step.get() {
+            write!(stdout, "\n[enter] next  [p] play  [q] quit > ").unwrap();
+        } else {
+            write!(stdout, "\n[enter] pause  [q] quit").unwrap();
+        }
+        stdout.flush().unwrap();
+        drop(stdout);
+
+        if self.step.get() {
+            // a closed stdin can't step any further, so play the rest
+            match self.keys.recv().as_deref() {
+                Ok("p") | Err(_) => self.step.set(false),
+                Ok("q") => self.stopped.set(true),
+                Ok(_) => (),
+            }
+        } else {
+            thread::sleep(self.frame_time);
+            match self.keys.try_recv().as_deref() {
+                Ok("q") => self.stopped.set(true),
+                Ok(_) => self.step.set(true),
+                Err(TryRecvError::Empty) | Err(TryRecvError::Disconnected) => (),
+            }
+        }
+    }
+}
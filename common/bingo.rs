@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::io::BufRead;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Query {
+    FirstWin,
+    LastWin,
+}
+
+#[derive(Debug, Clone)]
+struct Board {
+    size: usize,
+    cells: Vec<i32>,
+    marked: Vec<bool>,
+    row_hits: Vec<usize>,
+    col_hits: Vec<usize>,
+    diag_hits: [usize; 2],
+    unmarked_sum: i32,
+}
+
+#[derive(Debug)]
+struct BingoInput {
+    draw_order: Vec<i32>,
+    boards: Vec<Board>,
+}
+
+#[derive(Debug)]
+struct Win {
+    board: usize,
+    draw_idx: usize,
+    draw_val: i32,
+    score: i32,
+}
+
+fn load_from_file(file_path: &str) -> BingoInput {
+    let file = File::open(file_path).expect("file wasn't found.");
+    let mut reader = BufReader::new(file);
+
+    // read first line
+    let mut buf = String::new();
+    reader.read_line(&mut buf).unwrap();
+    let draw_order: Vec<i32> = buf.split(',').map(|val| val.trim().parse::<i32>().unwrap()).collect();
+
+    // read bingo boards, separated by empty lines
+    let mut boards: Vec<Board> = vec![];
+    let mut rows: Vec<Vec<i32>> = vec![];
+    for line in reader.lines() {
+        let line_str = line.unwrap();
+        if line_str.trim().is_empty() {
+            if !rows.is_empty() {
+                boards.push(Board::from_rows(&rows));
+                rows.clear();
+            }
+        } else {
+            rows.push(line_str.split_whitespace().map(|val| val.parse::<i32>().unwrap()).collect());
+        }
+    }
+    if !rows.is_empty() {
+        boards.push(Board::from_rows(&rows));
+    }
+
+    BingoInput { draw_order, boards }
+}
+
+impl Board {
+    fn from_rows(rows: &[Vec<i32>]) -> Board {
+        let size = rows.len();
+        if let Some(row) = rows.iter().find(|row| row.len() != size) {
+            panic!("board with {:} rows has a row of {:} numbers: {:?}", size, row.len(), row);
+        }
+
+        let cells = rows.concat();
+        Board {
+            size,
+            unmarked_sum: cells.iter().sum(),
+            cells,
+            marked: vec![false; size * size],
+            row_hits: vec![0; size],
+            col_hits: vec![0; size],
+            diag_hits: [0; 2],
+        }
+    }
+
+    // Marks a single cell and tells whether it completed a row, a column or (optionally) a diagonal.
+    fn mark(&mut self, row: usize, col: usize, diagonals: bool) -> bool {
+        let n = self.size;
+        let idx = row * n + col;
+        if self.marked[idx] {
+            return false;
+        }
+
+        self.marked[idx] = true;
+        self.unmarked_sum -= self.cells[idx];
+        self.row_hits[row] += 1;
+        self.col_hits[col] += 1;
+        if row == col {
+            self.diag_hits[0] += 1;
+        }
+        if row + col == n - 1 {
+            self.diag_hits[1] += 1;
+        }
+
+        self.row_hits[row] == n ||
+        self.col_hits[col] == n ||
+        (diagonals && (self.diag_hits[0] == n || self.diag_hits[1] == n))
+    }
+}
+
+// number -> every (board, row, col) it appears at
+fn index_boards(boards: &[Board]) -> HashMap<i32, Vec<(usize, usize, usize)>> {
+    let mut index: HashMap<i32, Vec<(usize, usize, usize)>> = HashMap::new();
+    for (board_idx, board) in boards.iter().enumerate() {
+        for (idx, &cell) in board.cells.iter().enumerate() {
+            index.entry(cell).or_default().push((board_idx, idx / board.size, idx % board.size));
+        }
+    }
+    index
+}
+
+// Plays the whole draw order and returns every board that wins, in the order they won.
+// Boards winning on the same draw are ordered by their position in the input.
+// Each draw only visits the cells holding the drawn number.
+fn rank_boards(mut board_input: BingoInput, diagonals: bool) -> Vec<Win> {
+    let index = index_boards(&board_input.boards);
+    let mut wins = Vec::new();
+    let mut won_on: Vec<Option<usize>> = vec![None; board_input.boards.len()];
+
+    for (draw_idx, &draw_val) in board_input.draw_order.iter().enumerate() {
+        let positions = match index.get(&draw_val) {
+            Some(positions) => positions,
+            None => continue,
+        };
+
+        let first_win = wins.len();
+        for &(board_idx, row, col) in positions {
+            if won_on[board_idx].is_some_and(|won_idx| won_idx < draw_idx) {
+                continue;
+            }
+
+            let board = &mut board_input.boards[board_idx];
+            if board.mark(row, col, diagonals) && won_on[board_idx].is_none() {
+                won_on[board_idx] = Some(draw_idx);
+                wins.push(Win {
+                    board: board_idx,
+                    draw_idx,
+                    draw_val,
+                    score: 0,
+                });
+            }
+        }
+
+        // a board may hold the number more than once, so score only after all of it got marked
+        for win in &mut wins[first_win..] {
+            win.score = draw_val * board_input.boards[win.board].unmarked_sum;
+        }
+    }
+
+    wins
+}
+
+// the whole day04 command line, part 1 & 2 only differ in which win is asked for by default
+pub fn run(default_query: Query) {
+    let args: Vec<String> = std::env::args().collect();
+    let diagonals = args.iter().any(|arg| arg == "--diagonals");
+    let show_ranking = args.iter().any(|arg| arg == "--ranking");
+    let query = if args.iter().any(|arg| arg == "--first") {
+        Query::FirstWin
+    } else if args.iter().any(|arg| arg == "--last") {
+        Query::LastWin
+    } else {
+        default_query
+    };
+
+    let board_input = load_from_file("data.in");
+    let wins = rank_boards(board_input, diagonals);
+
+    let win = match query {
+        Query::FirstWin => wins.first(),
+        Query::LastWin => wins.last(),
+    };
+    println!("{:}", win.expect("Failed to find bingo").score);
+
+    if show_ranking {
+        for (rank, win) in wins.iter().enumerate() {
+            println!("#{:} board {:} on draw {:} ({:}), score {:}", rank + 1, win.board, win.draw_idx, win.draw_val, win.score);
+        }
+    }
+}
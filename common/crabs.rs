@@ -0,0 +1,263 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::io::BufRead;
+
+use crate::repl::{self, Command};
+
+#[derive(Debug, Clone, Copy)]
+struct Crab {
+    pos: i64,
+    weight: i64,
+}
+
+// Fuel a single crab needs to reach `pos`. The search below relies on the
+// total cost being convex in `pos`, which holds for any cost convex in the distance.
+trait CostFunction {
+    fn move_cost(&self, crab: &Crab, pos: i64) -> i128;
+
+    // a position known to be optimal, if there's a shortcut to it
+    fn closed_form_position(&self, _crabs: &[Crab]) -> Option<i64> {
+        None
+    }
+}
+
+struct Linear;
+struct Triangular;
+struct Quadratic;
+
+// scales another cost function by each crab's weight
+struct Weighted(Box<dyn CostFunction>);
+
+fn get_dist(crab: &Crab, pos: i64) -> i128 {
+    (crab.pos - pos).abs() as i128
+}
+
+impl CostFunction for Linear {
+    fn move_cost(&self, crab: &Crab, pos: i64) -> i128 {
+        get_dist(crab, pos)
+    }
+
+    // the sum of distances is minimal at the median
+    fn closed_form_position(&self, crabs: &[Crab]) -> Option<i64> {
+        let mut positions: Vec<i64> = crabs.iter().map(|crab| crab.pos).collect();
+        let mid = positions.len() / 2;
+        let (_, &mut median, _) = positions.select_nth_unstable(mid);
+        Some(median)
+    }
+}
+
+impl CostFunction for Triangular {
+    fn move_cost(&self, crab: &Crab, pos: i64) -> i128 {
+        let dist = get_dist(crab, pos);
+        dist * (dist + 1) / 2
+    }
+
+    // The slope sum(pos - crab) + sum(sign(pos - crab)) / 2 changes sign
+    // within 1/2 of the mean, so only the integers next to the mean need to be checked.
+    fn closed_form_position(&self, crabs: &[Crab]) -> Option<i64> {
+        let n = crabs.len() as i128;
+        let sum: i128 = crabs.iter().map(|crab| crab.pos as i128).sum();
+        let mean_floor = sum.div_euclid(n) as i64;
+        let (min, max) = get_range(crabs);
+
+        (mean_floor - 1..=mean_floor + 2)
+            .map(|pos| pos.clamp(min, max))
+            .min_by_key(|&pos| get_align_cost(self, crabs, pos))
+    }
+}
+
+impl CostFunction for Quadratic {
+    fn move_cost(&self, crab: &Crab, pos: i64) -> i128 {
+        let dist = get_dist(crab, pos);
+        dist * dist
+    }
+}
+
+impl CostFunction for Weighted {
+    fn move_cost(&self, crab: &Crab, pos: i64) -> i128 {
+        crab.weight as i128 * self.0.move_cost(crab, pos)
+    }
+}
+
+fn get_cost_function(name: &str, weighted: bool) -> Box<dyn CostFunction> {
+    let cost: Box<dyn CostFunction> = match name {
+        "linear" => Box::new(Linear),
+        "triangular" => Box::new(Triangular),
+        "quadratic" => Box::new(Quadratic),
+        _ => panic!("Invalid cost function {:?}", name),
+    };
+    if weighted { Box::new(Weighted(cost)) } else { cost }
+}
+
+// crabs are listed as `pos` or `pos:weight`, the weight defaulting to 1
+fn load_from_file(file_path: &str) -> Vec<Crab> {
+    let file = File::open(file_path).unwrap_or_else(|_| panic!("File not found: {:}", file_path));
+    let mut reader = BufReader::new(file);
+
+    let mut buf: String = String::new();
+    reader.read_line(&mut buf).unwrap();
+
+    buf.split(',')
+        .map(|val| match val.trim().split_once(':') {
+            Some((pos, weight)) => Crab { pos: pos.parse::<i64>().unwrap(), weight: weight.parse::<i64>().unwrap() },
+            None => Crab { pos: val.trim().parse::<i64>().unwrap(), weight: 1 },
+        })
+        .collect()
+}
+
+fn get_range(crabs: &[Crab]) -> (i64, i64) {
+    let min = crabs.iter().map(|crab| crab.pos).min().unwrap();
+    let max = crabs.iter().map(|crab| crab.pos).max().unwrap();
+    (min, max)
+}
+
+fn get_align_cost(cost: &(impl CostFunction + ?Sized), crabs: &[Crab], pos: i64) -> i128 {
+    crabs.iter().map(|crab| cost.move_cost(crab, pos)).sum()
+}
+
+// ternary search over the crabs' range, works for any convex total cost
+fn find_optimal_position(cost: &dyn CostFunction, crabs: &[Crab]) -> i64 {
+    let (mut lo, mut hi) = get_range(crabs);
+    while hi - lo > 2 {
+        let m1 = lo + (hi - lo) / 3;
+        let m2 = hi - (hi - lo) / 3;
+        let (c1, c2) = (get_align_cost(cost, crabs, m1), get_align_cost(cost, crabs, m2));
+        if c1 < c2 {
+            hi = m2 - 1;
+        } else if c1 > c2 {
+            lo = m1 + 1;
+        } else {
+            lo = m1;
+            hi = m2;
+        }
+    }
+
+    (lo..=hi).min_by_key(|&pos| get_align_cost(cost, crabs, pos)).unwrap()
+}
+
+// Returns the min cost and the range of positions reaching it. The cost is convex, so it
+// only decreases towards `best` from the left & increases after it, which lets both ends
+// of the optimal range be found by binary search.
+fn find_min_cost(cost: &dyn CostFunction, crabs: &[Crab]) -> (i128, i64, i64) {
+    let best = cost
+        .closed_form_position(crabs)
+        .unwrap_or_else(|| find_optimal_position(cost, crabs));
+    let min_cost = get_align_cost(cost, crabs, best);
+    let (min, max) = get_range(crabs);
+
+    let (mut lo, mut hi) = (min, best);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if get_align_cost(cost, crabs, mid) == min_cost { hi = mid; } else { lo = mid + 1; }
+    }
+    let first = lo;
+
+    let (mut lo, mut hi) = (best, max);
+    while lo < hi {
+        let mid = hi - (hi - lo) / 2;
+        if get_align_cost(cost, crabs, mid) == min_cost { lo = mid; } else { hi = mid - 1; }
+    }
+    let last = hi;
+
+    (min_cost, first, last)
+}
+
+// tries every position, O(crabs * range)
+fn find_min_cost_brute_force(cost: &dyn CostFunction, crabs: &[Crab]) -> (i128, i64, i64) {
+    let (min, max) = get_range(crabs);
+    let costs: Vec<(i64, i128)> = (min..=max).map(|pos| (pos, get_align_cost(cost, crabs, pos))).collect();
+    let min_cost = costs.iter().map(|&(_, cost)| cost).min().unwrap();
+    let mut optimal = costs.iter().filter(|&&(_, cost)| cost == min_cost).map(|&(pos, _)| pos);
+    let first = optimal.next().unwrap();
+    (min_cost, first, optimal.next_back().unwrap_or(first))
+}
+
+struct Session {
+    crabs: Vec<Crab>,
+    cost_name: String,
+    weighted: bool,
+    cost: Box<dyn CostFunction>,
+}
+
+const REPL_COMMANDS: [Command<Session>; 5] = [
+    Command {
+        name: "load",
+        usage: "load PATH            read crabs from another file",
+        run: |session, args| {
+            session.crabs = load_from_file(args.first().ok_or("missing path")?);
+            println!("{:} crabs", session.crabs.len());
+            Ok(())
+        },
+    },
+    Command {
+        name: "cost",
+        usage: "cost NAME [weighted] switch to the linear, triangular or quadratic cost",
+        run: |session, args| {
+            let name = args.first().ok_or("missing cost name")?;
+            session.weighted = args.get(1) == Some(&"weighted");
+            session.cost = get_cost_function(name, session.weighted);
+            session.cost_name = name.to_string();
+            Ok(())
+        },
+    },
+    Command {
+        name: "show",
+        usage: "show                 print the crabs' range & the cost function",
+        run: |session, _| {
+            let (min, max) = get_range(&session.crabs);
+            let weighted = if session.weighted { " (weighted)" } else { "" };
+            println!("{:} crabs at {:}..={:}, {:} cost{:}", session.crabs.len(), min, max, session.cost_name, weighted);
+            Ok(())
+        },
+    },
+    Command {
+        name: "eval",
+        usage: "eval POS             fuel needed to align at POS",
+        run: |session, args| {
+            let pos = args.first().and_then(|val| val.parse::<i64>().ok()).ok_or("eval expects a position")?;
+            println!("{:}", get_align_cost(session.cost.as_ref(), &session.crabs, pos));
+            Ok(())
+        },
+    },
+    Command {
+        name: "best",
+        usage: "best                 min fuel & the positions reaching it",
+        run: |session, _| {
+            let (min_cost, first, last) = find_min_cost(session.cost.as_ref(), &session.crabs);
+            println!("{:} at {:}..={:}", min_cost, first, last);
+            Ok(())
+        },
+    },
+];
+
+// the whole day07 command line, part 1 & 2 only differ in the default cost function
+pub fn run(default_cost: &str) {
+    let args: Vec<String> = std::env::args().collect();
+    let verify = args.iter().any(|arg| arg == "--verify");
+    let weighted = args.iter().any(|arg| arg == "--weighted");
+    let cost_name = args
+        .iter()
+        .position(|arg| arg == "--cost")
+        .map_or(default_cost, |pos| args.get(pos + 1).expect("--cost expects a name").as_str());
+
+    let cost = get_cost_function(cost_name, weighted);
+    let crabs = load_from_file("data.in");
+
+    if args.iter().any(|arg| arg == "--repl") {
+        let mut session = Session { crabs, cost_name: cost_name.to_string(), weighted, cost };
+        repl::run(&mut session, &REPL_COMMANDS);
+        return;
+    }
+
+    let (min_cost, first, last) = find_min_cost(cost.as_ref(), &crabs);
+    if verify {
+        assert_eq!((min_cost, first, last), find_min_cost_brute_force(cost.as_ref(), &crabs), "search differs from brute force");
+    }
+
+    println!("{:}", min_cost);
+    if first == last {
+        println!("optimal position: {:}", first);
+    } else {
+        println!("optimal positions: {:}..={:}", first, last);
+    }
+}
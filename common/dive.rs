@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::Read;
+use std::io::Write;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Model {
+    // up/down change the depth directly
+    Simple,
+    // up/down change the aim, forward dives along it
+    Aim,
+}
+
+#[derive(Debug, Default, Clone)]
+struct Submarine {
+    horizontal: i64,
+    depth: i64,
+    aim: i64,
+}
+
+type CommandFn = fn(&mut Submarine, Model, i64);
+
+struct CommandTable {
+    commands: HashMap<String, CommandFn>,
+}
+
+impl CommandTable {
+    fn new() -> CommandTable {
+        CommandTable { commands: HashMap::new() }
+    }
+
+    fn with_defaults() -> CommandTable {
+        let mut table = CommandTable::new();
+        table.register("forward", |sub, model, amount| {
+            sub.horizontal += amount;
+            if model == Model::Aim {
+                sub.depth += sub.aim * amount;
+            }
+        });
+        table.register("back", |sub, model, amount| {
+            sub.horizontal -= amount;
+            if model == Model::Aim {
+                sub.depth -= sub.aim * amount;
+            }
+        });
+        table.register("down", |sub, model, amount| match model {
+            Model::Simple => sub.depth += amount,
+            Model::Aim => sub.aim += amount,
+        });
+        table.register("up", |sub, model, amount| match model {
+            Model::Simple => sub.depth -= amount,
+            Model::Aim => sub.aim -= amount,
+        });
+        table.register("set-aim", |sub, _, amount| sub.aim = amount);
+        table
+    }
+
+    fn register(&mut self, name: &str, command: CommandFn) {
+        self.commands.insert(name.to_string(), command);
+    }
+
+    fn get(&self, name: &str) -> Option<CommandFn> {
+        self.commands.get(name).copied()
+    }
+}
+
+#[derive(Debug)]
+enum Instruction {
+    Command { name: String, amount: i64 },
+    Repeat { times: u64, body: Vec<Instruction> },
+}
+
+#[derive(Debug, Default)]
+struct Summary {
+    steps: usize,
+    max_depth: i64,
+    max_depth_step: usize,
+    total_distance: i64,
+}
+
+impl Summary {
+    fn update(&mut self, prev: &Submarine, sub: &Submarine) {
+        self.steps += 1;
+        self.total_distance += (sub.horizontal - prev.horizontal).abs() + (sub.depth - prev.depth).abs();
+        if sub.depth > self.max_depth {
+            self.max_depth = sub.depth;
+            self.max_depth_step = self.steps;
+        }
+    }
+}
+
+struct Options {
+    model: Model,
+    input: String,
+    trajectory: Option<String>,
+    summary: bool,
+}
+
+fn parse_args(args: &[String], default_model: Model) -> Options {
+    let mut options = Options {
+        model: default_model,
+        input: String::from("data.in"),
+        trajectory: None,
+        summary: false,
+    };
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--model" => {
+                options.model = match iter.next().map(String::as_str) {
+                    Some("simple") => Model::Simple,
+                    Some("aim") => Model::Aim,
+                    val => panic!("--model expects simple or aim, got {:?}", val),
+                };
+            },
+            "--input" => options.input = iter.next().expect("--input expects a file path").clone(),
+            "--trajectory" => options.trajectory = Some(iter.next().expect("--trajectory expects a file path").clone()),
+            "--summary" => options.summary = true,
+            _ => panic!("Invalid argument {:?}", arg),
+        }
+    }
+    options
+}
+
+fn load_from_file(file_path: &str) -> String {
+    let file = File::open(file_path).expect("file wasn't found.");
+    let mut reader = BufReader::new(file);
+
+    let mut buf = String::new();
+    reader.read_to_string(&mut buf).unwrap();
+    buf
+}
+
+// Strips '#' comments and splits the script into words, braces always being words of their own.
+fn tokenize(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .map(|line| line.split('#').next().unwrap())
+        .flat_map(|line| line.replace('{', " { ").replace('}', " } ").split_whitespace().map(String::from).collect::<Vec<String>>())
+        .collect()
+}
+
+fn parse_block<'a>(tokens: &mut impl Iterator<Item = &'a String>, table: &CommandTable, nested: bool) -> Vec<Instruction> {
+    let mut block = Vec::new();
+    while let Some(token) = tokens.next() {
+        match token.as_str() {
+            "}" if nested => return block,
+            "repeat" => {
+                let times = tokens
+                    .next()
+                    .and_then(|val| val.parse::<u64>().ok())
+                    .expect("repeat expects a count");
+                if tokens.next().map(String::as_str) != Some("{") {
+                    panic!("repeat {:} expects a '{{' block", times);
+                }
+                block.push(Instruction::Repeat { times, body: parse_block(tokens, table, true) });
+            },
+            name => {
+                if table.get(name).is_none() {
+                    panic!("Invalid command {:?}", name);
+                }
+                let amount = tokens
+                    .next()
+                    .and_then(|val| val.parse::<i64>().ok())
+                    .unwrap_or_else(|| panic!("{:} expects an amount", name));
+                block.push(Instruction::Command { name: name.to_string(), amount });
+            },
+        }
+    }
+
+    if nested {
+        panic!("Unclosed repeat block");
+    }
+    block
+}
+
+fn parse_program(source: &str, table: &CommandTable) -> Vec<Instruction> {
+    let tokens = tokenize(source);
+    parse_block(&mut tokens.iter(), table, false)
+}
+
+// `on_step` sees every executed command together with the state it left the submarine in.
+fn execute(program: &[Instruction], table: &CommandTable, model: Model, sub: &mut Submarine, on_step: &mut dyn FnMut(&str, i64, &Submarine)) {
+    for instruction in program {
+        match instruction {
+            Instruction::Command { name, amount } => {
+                table.get(name).unwrap()(sub, model, *amount);
+                on_step(name, *amount, sub);
+            },
+            Instruction::Repeat { times, body } => {
+                for _ in 0..*times {
+                    execute(body, table, model, sub, on_step);
+                }
+            },
+        }
+    }
+}
+
+// the whole day02 command line, part 1 & 2 only differ in the default model
+pub fn run(default_model: Model) {
+    let args: Vec<String> = std::env::args().collect();
+    let options = parse_args(&args, default_model);
+
+    let table = CommandTable::with_defaults();
+    let program = parse_program(&load_from_file(&options.input), &table);
+
+    let mut trajectory = options.trajectory.as_ref().map(|path| {
+        let file = File::create(path).expect("couldn't create trajectory file.");
+        let mut writer = BufWriter::new(file);
+        writeln!(writer, "step,command,amount,horizontal,depth,aim").unwrap();
+        writer
+    });
+
+    let mut sub = Submarine::default();
+    let mut prev = sub.clone();
+    let mut summary = Summary::default();
+    execute(&program, &table, options.model, &mut sub, &mut |name, amount, sub| {
+        summary.update(&prev, sub);
+        prev = sub.clone();
+        if let Some(writer) = trajectory.as_mut() {
+            writeln!(writer, "{:},{:},{:},{:},{:},{:}", summary.steps, name, amount, sub.horizontal, sub.depth, sub.aim).unwrap();
+        }
+    });
+
+    println!("{:}", sub.depth * sub.horizontal);
+    if options.summary {
+        println!("max depth: {:} (step {:})", summary.max_depth, summary.max_depth_step);
+        println!("total distance: {:}", summary.total_distance);
+    }
+}
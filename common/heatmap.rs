@@ -0,0 +1,72 @@
+use std::fs::File;
+use std::io::BufWriter;
+use std::io::Write;
+
+// larger maps are better looked at through a smaller crop of the input
+const MAX_CELLS: usize = 100_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Scale {
+    Linear,
+    Log,
+}
+
+// How many lines cover each point, with (0, 0) in the top left corner like in the puzzle text.
+#[derive(Debug)]
+pub struct HeatMap {
+    cols: usize,
+    rows: usize,
+    counts: Vec<u32>,
+}
+
+impl HeatMap {
+    pub fn new(max_x: i32, max_y: i32) -> HeatMap {
+        let cols = max_x as usize + 1;
+        let rows = max_y as usize + 1;
+        if cols * rows > MAX_CELLS {
+            panic!("{:}x{:} is too large for a heatmap", cols, rows);
+        }
+
+        HeatMap { cols, rows, counts: vec![0; cols * rows] }
+    }
+
+    pub fn add(&mut self, x: i32, y: i32) {
+        self.counts[y as usize * self.cols + x as usize] += 1;
+    }
+
+    // '.' for points no line covers, the number of lines otherwise ('+' above 9)
+    pub fn to_ascii(&self) -> String {
+        let mut res = String::with_capacity((self.cols + 1) * self.rows);
+        for row in self.counts.chunks(self.cols) {
+            for &count in row {
+                res.push(match count {
+                    0 => '.',
+                    1..=9 => char::from_digit(count, 10).unwrap(),
+                    _ => '+',
+                });
+            }
+            res.push('\n');
+        }
+        res
+    }
+
+    // binary greyscale PGM, the most covered point is white
+    pub fn write_pgm(&self, file_path: &str, scale: Scale) {
+        let file = File::create(file_path).unwrap_or_else(|_| panic!("Can't create {:}", file_path));
+        let mut writer = BufWriter::new(file);
+
+        let max = self.counts.iter().copied().max().unwrap_or(0).max(1) as f64;
+        write!(writer, "P5\n{:} {:}\n255\n", self.cols, self.rows).unwrap();
+        let pixels: Vec<u8> = self.counts
+            .iter()
+            .map(|&count| {
+                let level = match scale {
+                    Scale::Linear => count as f64 / max,
+                    Scale::Log => (1.0 + count as f64).ln() / (1.0 + max).ln(),
+                };
+                (level * 255.0).round() as u8
+            })
+            .collect();
+        writer.write_all(&pixels).unwrap();
+    }
+}
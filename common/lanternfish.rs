@@ -0,0 +1,314 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::io::BufRead;
+use std::io::BufWriter;
+use std::io::Write;
+use num_bigint::BigUint;
+
+const DEFAULT_NAME: &str = "lanternfish";
+// exact populations grow by ~0.13 bits a day, beyond this they take too long to compute
+const MAX_EXACT_DAYS: u64 = 1_000_000;
+const RESET_TIMER: usize = 6;
+const NEWBORN_TIMER: usize = 8;
+
+type Matrix = Vec<Vec<BigUint>>;
+
+// A fish's timer counts down every day. After a day at 0 it spawns a fish with
+// timer `newborn` and starts over at `reset`. With a lifespan of K it dies
+// instead of starting over once it has spawned K times.
+#[derive(Debug, Clone)]
+struct Species {
+    name: String,
+    reset: usize,
+    newborn: usize,
+    lifespan: Option<usize>,
+    fish: Vec<u32>,
+}
+
+impl Species {
+    fn timers(&self) -> usize {
+        self.reset.max(self.newborn) + 1
+    }
+
+    fn cycles(&self) -> usize {
+        self.lifespan.unwrap_or(1)
+    }
+
+    // bucket of fish with the given timer which have spawned `cycle` times so far
+    fn bucket(&self, timer: usize, cycle: usize) -> usize {
+        cycle * self.timers() + timer
+    }
+
+    fn get_buckets(&self) -> Vec<u64> {
+        let mut buf: Vec<u64> = vec![0; self.timers() * self.cycles()];
+        for f in &self.fish {
+            if *f as usize >= self.timers() {
+                panic!("{:} timer {:} is above the max of {:}", self.name, f, self.timers() - 1);
+            }
+            buf[self.bucket(*f as usize, 0)] += 1;
+        }
+        buf
+    }
+}
+
+// Each line holds the starting timers of one species, optionally prefixed with its model:
+//   3,4,3,1,2
+//   glowfish reset=4 newborn=5 lifespan=3: 1,2,2
+// Unset parameters fall back to `default`.
+fn load_from_file(file_path: &str, default: &Species) -> Vec<Species> {
+    let file = File::open(file_path).unwrap_or_else(|_| panic!("File not found: {:}", file_path));
+    let reader = BufReader::new(file);
+
+    let mut res = Vec::new();
+    for line in reader.lines() {
+        let line_str = line.unwrap();
+        if line_str.trim().is_empty() {
+            continue;
+        }
+
+        let mut species = default.clone();
+        let timers = match line_str.split_once(':') {
+            Some((model, timers)) => {
+                let mut params = model.split_whitespace();
+                species.name = params.next().expect("species name missing").to_string();
+                for param in params {
+                    match param.split_once('=') {
+                        Some(("reset", val)) => species.reset = val.parse::<usize>().unwrap(),
+                        Some(("newborn", val)) => species.newborn = val.parse::<usize>().unwrap(),
+                        Some(("lifespan", val)) => species.lifespan = Some(parse_lifespan(val)),
+                        _ => panic!("Invalid species parameter {:?}", param),
+                    }
+                }
+                timers
+            },
+            None => line_str.as_str(),
+        };
+
+        species.fish = timers.split(',').map(|val| val.trim().parse::<u32>().unwrap()).collect();
+        res.push(species);
+    }
+
+    res
+}
+
+// a fish lives at least one cycle, 0 would leave no timer buckets to simulate
+fn parse_lifespan(val: &str) -> usize {
+    let lifespan = val.parse::<usize>().unwrap_or_else(|_| panic!("Invalid lifespan {:?}", val));
+    if lifespan < 1 {
+        panic!("lifespan must be at least 1, got {:}", lifespan);
+    }
+    lifespan
+}
+
+// accepts plain numbers as well as powers like 10^15
+fn parse_number(val: &str) -> u64 {
+    match val.split_once('^') {
+        Some((base, exp)) => base
+            .parse::<u64>()
+            .unwrap()
+            .checked_pow(exp.parse::<u32>().unwrap())
+            .unwrap_or_else(|| panic!("{:} doesn't fit in 64 bits", val)),
+        None => val.parse::<u64>().unwrap(),
+    }
+}
+
+// next_buf[i] = sum of matrix[i][j] * buf[j] for a single day
+fn get_transition_matrix(species: &Species) -> Matrix {
+    let size = species.timers() * species.cycles();
+    let mut matrix = vec![vec![BigUint::from(0u32); size]; size];
+    for cycle in 0..species.cycles() {
+        for timer in 1..species.timers() {
+            matrix[species.bucket(timer - 1, cycle)][species.bucket(timer, cycle)] = BigUint::from(1u32);
+        }
+
+        let spawning = species.bucket(0, cycle);
+        matrix[species.bucket(species.newborn, 0)][spawning] += 1u32;
+        match species.lifespan {
+            Some(lifespan) if cycle + 1 >= lifespan => (),
+            Some(_) => matrix[species.bucket(species.reset, cycle + 1)][spawning] += 1u32,
+            None => matrix[species.bucket(species.reset, cycle)][spawning] += 1u32,
+        }
+    }
+    matrix
+}
+
+fn reduce(val: BigUint, modulo: Option<&BigUint>) -> BigUint {
+    match modulo {
+        Some(m) => val % m,
+        None => val,
+    }
+}
+
+fn mat_mul(a: &Matrix, b: &Matrix, modulo: Option<&BigUint>) -> Matrix {
+    let size = a.len();
+    let mut res = vec![vec![BigUint::from(0u32); size]; size];
+    for (i, row) in res.iter_mut().enumerate() {
+        for (j, cell) in row.iter_mut().enumerate() {
+            let sum = (0..size).fold(BigUint::from(0u32), |acc, k| acc + &a[i][k] * &b[k][j]);
+            *cell = reduce(sum, modulo);
+        }
+    }
+    res
+}
+
+// square & multiply, so a day count of 10^15 only takes ~50 squarings
+fn mat_pow(matrix: &Matrix, mut exp: u64, modulo: Option<&BigUint>) -> Matrix {
+    let size = matrix.len();
+    let mut res: Matrix = (0..size)
+        .map(|i| (0..size).map(|j| BigUint::from((i == j) as u32)).collect())
+        .collect();
+    let mut base = matrix.clone();
+    while exp > 0 {
+        if exp & 1 == 1 {
+            res = mat_mul(&res, &base, modulo);
+        }
+        base = mat_mul(&base, &base, modulo);
+        exp >>= 1;
+    }
+    res
+}
+
+fn get_population(species: &Species, days: u64, modulo: Option<&BigUint>) -> BigUint {
+    let buf = species.get_buckets();
+    let matrix = mat_pow(&get_transition_matrix(species), days, modulo);
+    let total = matrix
+        .iter()
+        .flat_map(|row| row.iter().zip(buf.iter()))
+        .fold(BigUint::from(0u32), |acc, (cell, &count)| acc + cell * count);
+    reduce(total, modulo)
+}
+
+fn mat_vec(matrix: &Matrix, buf: &[BigUint], modulo: Option<&BigUint>) -> Vec<BigUint> {
+    matrix
+        .iter()
+        .map(|row| reduce(row.iter().zip(buf.iter()).fold(BigUint::from(0u32), |acc, (cell, count)| acc + cell * count), modulo))
+        .collect()
+}
+
+// Steps every species one day at a time and calls `on_day` with their buckets for day 0 up to `days`,
+// until it returns false.
+fn simulate_days(species: &[Species], days: u64, modulo: Option<&BigUint>, mut on_day: impl FnMut(u64, &[Vec<BigUint>]) -> bool) {
+    let matrices: Vec<Matrix> = species.iter().map(get_transition_matrix).collect();
+    let mut bufs: Vec<Vec<BigUint>> = species
+        .iter()
+        .map(|species| species.get_buckets().into_iter().map(BigUint::from).collect())
+        .collect();
+
+    for day in 0..=days {
+        if !on_day(day, &bufs) {
+            return;
+        }
+        bufs = matrices.iter().zip(bufs.iter()).map(|(matrix, buf)| mat_vec(matrix, buf, modulo)).collect();
+    }
+}
+
+// fish per timer value, regardless of how often they have spawned already
+fn get_timer_counts(species: &Species, buf: &[BigUint]) -> Vec<BigUint> {
+    (0..species.timers())
+        .map(|timer| (0..species.cycles()).fold(BigUint::from(0u32), |acc, cycle| acc + &buf[species.bucket(timer, cycle)]))
+        .collect()
+}
+
+// one row per day & species, or a JSON array of days if the path ends in .json
+fn write_series(file_path: &str, species: &[Species], days: u64, modulo: Option<&BigUint>) {
+    let file = File::create(file_path).unwrap_or_else(|_| panic!("Can't create {:}", file_path));
+    let mut writer = BufWriter::new(file);
+    let json = file_path.ends_with(".json");
+    let max_timers = species.iter().map(Species::timers).max().unwrap_or(0);
+
+    if json {
+        writeln!(writer, "[").unwrap();
+    } else {
+        let timer_cols: Vec<String> = (0..max_timers).map(|timer| std::format!("timer_{:}", timer)).collect();
+        writeln!(writer, "day,species,total,{:}", timer_cols.join(",")).unwrap();
+    }
+
+    simulate_days(species, days, modulo, |day, bufs| {
+        let mut entries = Vec::new();
+        for (species, buf) in species.iter().zip(bufs.iter()) {
+            let mut timer_counts = get_timer_counts(species, buf);
+            let total = reduce(timer_counts.iter().sum(), modulo);
+            timer_counts.resize(max_timers, BigUint::from(0u32));
+            let timer_strs: Vec<String> = timer_counts.iter().map(BigUint::to_string).collect();
+            if json {
+                entries.push(std::format!("{{\"name\": \"{:}\", \"total\": {:}, \"timers\": [{:}]}}", species.name, total, timer_strs.join(", ")));
+            } else {
+                writeln!(writer, "{:},{:},{:},{:}", day, species.name, total, timer_strs.join(",")).unwrap();
+            }
+        }
+
+        if json {
+            let separator = if day == days { "" } else { "," };
+            writeln!(writer, "  {{\"day\": {:}, \"species\": [{:}]}}{:}", day, entries.join(", "), separator).unwrap();
+        }
+        true
+    });
+
+    if json {
+        writeln!(writer, "]").unwrap();
+    }
+}
+
+// first day on which all species together number more than `threshold` fish
+fn find_threshold_day(species: &[Species], days: u64, threshold: &BigUint) -> Option<u64> {
+    let mut res = None;
+    simulate_days(species, days, None, |day, bufs| {
+        let total: BigUint = bufs.iter().flat_map(|buf| buf.iter()).sum();
+        if total > *threshold {
+            res = Some(day);
+        }
+        // a population that died out stays at 0
+        res.is_none() && total > BigUint::from(0u32)
+    });
+    res
+}
+
+// the whole day06 command line, part 1 & 2 only differ in the default day count
+pub fn run(default_days: u64) {
+    let args: Vec<String> = std::env::args().collect();
+    let get_arg = |name: &str| args.iter().position(|arg| arg == name).and_then(|pos| args.get(pos + 1));
+    let days = get_arg("--days").map_or(default_days, |val| parse_number(val));
+    let modulo = get_arg("--modulo").map(|val| match parse_number(val) {
+        0 => panic!("--modulo must be at least 1"),
+        m => BigUint::from(m),
+    });
+    if days > MAX_EXACT_DAYS && modulo.is_none() {
+        panic!("--days above {:} needs --modulo P, the exact population would be too large", MAX_EXACT_DAYS);
+    }
+    let threshold = get_arg("--threshold").map(|val| BigUint::from(parse_number(val)));
+    let series_path = get_arg("--series");
+    if days > MAX_EXACT_DAYS && (threshold.is_some() || series_path.is_some()) {
+        panic!("--threshold & --series step through every day, they need --days {:} or less", MAX_EXACT_DAYS);
+    }
+    let default = Species {
+        name: DEFAULT_NAME.to_string(),
+        reset: get_arg("--reset").map_or(RESET_TIMER, |val| val.parse::<usize>().unwrap()),
+        newborn: get_arg("--newborn").map_or(NEWBORN_TIMER, |val| val.parse::<usize>().unwrap()),
+        lifespan: get_arg("--lifespan").map(|val| parse_lifespan(val)),
+        fish: Vec::new(),
+    };
+
+    let species = load_from_file("data.in", &default);
+    let populations: Vec<BigUint> = species
+        .iter()
+        .map(|species| get_population(species, days, modulo.as_ref()))
+        .collect();
+
+    let total = populations.iter().fold(BigUint::from(0u32), |acc, val| acc + val);
+    println!("{:}", reduce(total, modulo.as_ref()));
+    if species.len() > 1 {
+        for (species, population) in species.iter().zip(populations.iter()) {
+            println!("{:}: {:}", species.name, population);
+        }
+    }
+
+    if let Some(threshold) = threshold {
+        match find_threshold_day(&species, days, &threshold) {
+            Some(day) => println!("population exceeds {:} on day {:}", threshold, day),
+            None => println!("population stays at or below {:} for all {:} days", threshold, days),
+        }
+    }
+    if let Some(path) = series_path {
+        write_series(path, &species, days, modulo.as_ref());
+    }
+}
@@ -0,0 +1,161 @@
+// What every puzzle is about, the README's puzzle table is generated from this.
+pub struct Puzzle {
+    pub day: u32,
+    pub title: &'static str,
+    pub input_grammar: &'static str,
+    // what part 1 & part 2 compute
+    pub parts: [&'static str; 2],
+}
+
+pub const PUZZLES: [Puzzle; 25] = [
+    Puzzle {
+        day: 1,
+        title: "Sonar Sweep",
+        input_grammar: "one depth per line, e.g. `199`",
+        parts: ["measurements larger than the previous one", "increases of the sliding 3-measurement sum"],
+    },
+    Puzzle {
+        day: 2,
+        title: "Dive!",
+        input_grammar: "`forward|down|up N` per line",
+        parts: ["horizontal position × depth", "same, with `down`/`up` changing the aim"],
+    },
+    Puzzle {
+        day: 3,
+        title: "Binary Diagnostic",
+        input_grammar: "one binary number per line, e.g. `00100`",
+        parts: ["gamma × epsilon rate from the most/least common bits", "oxygen generator × CO2 scrubber rating"],
+    },
+    Puzzle {
+        day: 4,
+        title: "Giant Squid",
+        input_grammar: "comma separated draws, then 5x5 boards separated by empty lines",
+        parts: ["score of the first board to win", "score of the last board to win"],
+    },
+    Puzzle {
+        day: 5,
+        title: "Hydrothermal Venture",
+        input_grammar: "`x1,y1 -> x2,y2` per line",
+        parts: ["points covered by at least two horizontal/vertical lines", "same, including diagonal lines"],
+    },
+    Puzzle {
+        day: 6,
+        title: "Lanternfish",
+        input_grammar: "comma separated timers, e.g. `3,4,3,1,2`",
+        parts: ["lanternfish after 80 days", "lanternfish after 256 days"],
+    },
+    Puzzle {
+        day: 7,
+        title: "The Treachery of Whales",
+        input_grammar: "comma separated positions, e.g. `16,1,2`",
+        parts: ["least fuel to align, 1 per step", "least fuel to align, n-th step costs n"],
+    },
+    Puzzle {
+        day: 8,
+        title: "Seven Segment Search",
+        input_grammar: "10 patterns `|` 4 output patterns per line",
+        parts: ["outputs showing a 1, 4, 7 or 8", "sum of the decoded output values"],
+    },
+    Puzzle {
+        day: 9,
+        title: "Smoke Basin",
+        input_grammar: "grid of digits 0-9",
+        parts: ["sum of the low points' risk levels", "product of the three largest basins' sizes"],
+    },
+    Puzzle {
+        day: 10,
+        title: "Syntax Scoring",
+        input_grammar: "one line of `()[]{}<>` per line",
+        parts: ["syntax error score of the corrupted lines", "middle completion score of the incomplete lines"],
+    },
+    Puzzle {
+        day: 11,
+        title: "Dumbo Octopus",
+        input_grammar: "10x10 grid of digits",
+        parts: ["flashes after 100 steps", "first step on which all octopuses flash"],
+    },
+    Puzzle {
+        day: 12,
+        title: "Passage Pathing",
+        input_grammar: "`a-b` cave connections per line",
+        parts: ["paths visiting small caves at most once", "paths visiting a single small cave twice"],
+    },
+    Puzzle {
+        day: 13,
+        title: "Transparent Origami",
+        input_grammar: "`x,y` dots, empty line, `fold along x|y=N`",
+        parts: ["dots visible after the first fold", "the code spelled by the dots after all folds"],
+    },
+    Puzzle {
+        day: 14,
+        title: "Extended Polymerization",
+        input_grammar: "template, empty line, `AB -> C` rules",
+        parts: ["most - least common element after 10 steps", "same after 40 steps"],
+    },
+    Puzzle {
+        day: 15,
+        title: "Chiton",
+        input_grammar: "grid of digits 1-9",
+        parts: ["lowest total risk path through the grid", "same through the grid tiled 5x5"],
+    },
+    Puzzle {
+        day: 16,
+        title: "Packet Decoder",
+        input_grammar: "one hexadecimal transmission",
+        parts: ["sum of the packets' version numbers", "value of the outermost expression"],
+    },
+    Puzzle {
+        day: 17,
+        title: "Trick Shot",
+        input_grammar: "`target area: x=A..B, y=C..D`",
+        parts: ["highest y reachable while hitting the target", "initial velocities hitting the target"],
+    },
+    Puzzle {
+        day: 18,
+        title: "Snailfish",
+        input_grammar: "one snailfish number per line, e.g. `[[1,2],3]`",
+        parts: ["magnitude of the sum of all numbers", "largest magnitude of a sum of two numbers"],
+    },
+    Puzzle {
+        day: 19,
+        title: "Beacon Scanner",
+        input_grammar: "`--- scanner N ---` blocks of `x,y,z` beacons",
+        parts: ["number of distinct beacons", "largest Manhattan distance between scanners"],
+    },
+    Puzzle {
+        day: 20,
+        title: "Trench Map",
+        input_grammar: "512 char enhancement line, empty line, image of `#.`",
+        parts: ["lit pixels after 2 enhancements", "lit pixels after 50 enhancements"],
+    },
+    Puzzle {
+        day: 21,
+        title: "Dirac Dice",
+        input_grammar: "`Player N starting position: P` lines",
+        parts: ["losing score × die rolls with the deterministic die", "universes the more winning player wins in"],
+    },
+    Puzzle {
+        day: 22,
+        title: "Reactor Reboot",
+        input_grammar: "`on|off x=A..B,y=C..D,z=E..F` per line",
+        parts: ["lit cubes within -50..50", "lit cubes in total"],
+    },
+    Puzzle {
+        day: 23,
+        title: "Amphipod",
+        input_grammar: "the burrow drawn with `#.ABCD`",
+        parts: ["least energy to sort the amphipods", "same with the two extra folded rows"],
+    },
+    Puzzle {
+        day: 24,
+        title: "Arithmetic Logic Unit",
+        input_grammar: "ALU program of `inp|add|mul|div|mod|eql` instructions",
+        parts: ["largest valid model number", "smallest valid model number"],
+    },
+    Puzzle {
+        day: 25,
+        title: "Sea Cucumber",
+        input_grammar: "grid of `>v.`",
+        parts: ["first step on which no sea cucumber moves", "none, the last star is free"],
+    },
+];
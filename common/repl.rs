@@ -0,0 +1,50 @@
+use std::io::BufRead;
+use std::io::Write;
+use std::panic::{self, AssertUnwindSafe};
+
+// A command `run` gets the words following its name, errors are printed & the repl goes on.
+pub struct Command<S> {
+    pub name: &'static str,
+    pub usage: &'static str,
+    pub run: fn(&mut S, &[&str]) -> Result<(), String>,
+}
+
+// Reads one command per line from stdin until `quit` or the end of the input.
+// The solvers panic on bad input, so a panicking command is reported and the state kept.
+pub fn run<S>(state: &mut S, commands: &[Command<S>]) {
+    let stdin = std::io::stdin();
+    loop {
+        print!("> ");
+        std::io::stdout().flush().unwrap();
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).unwrap() == 0 {
+            println!();
+            return;
+        }
+
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let name = match words.first() {
+            Some(name) => *name,
+            None => continue,
+        };
+        match name {
+            "quit" | "exit" => return,
+            "help" => {
+                for command in commands {
+                    println!("  {:}", command.usage);
+                }
+                println!("  help");
+                println!("  quit");
+            },
+            _ => match commands.iter().find(|command| command.name == name) {
+                Some(command) => match panic::catch_unwind(AssertUnwindSafe(|| (command.run)(state, &words[1..]))) {
+                    Ok(Ok(())) => (),
+                    Ok(Err(err)) => println!("error: {:}", err),
+                    Err(_) => println!("error: {:} failed", name),
+                },
+                None => println!("unknown command {:?}, try help", name),
+            },
+        }
+    }
+}
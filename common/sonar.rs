@@ -0,0 +1,135 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::io::BufRead;
+
+const DEFAULT_REPORT_EVERY: usize = 1_000_000;
+
+pub struct Options {
+    pub window: usize,
+    pub stats: bool,
+    pub stream: bool,
+    pub report_every: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct SweepStats {
+    pub increases: Vec<usize>,
+    pub longest_increasing_run: usize,
+    pub largest_jump: Option<(usize, i64)>,
+}
+
+pub fn parse_args(args: &[String], default_window: usize) -> Options {
+    let mut options = Options {
+        window: default_window,
+        stats: false,
+        stream: false,
+        report_every: DEFAULT_REPORT_EVERY,
+    };
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--window" => {
+                options.window = iter
+                    .next()
+                    .and_then(|val| val.parse::<usize>().ok())
+                    .filter(|&window| window > 0)
+                    .expect("--window expects a positive number");
+            },
+            "--stats" => options.stats = true,
+            "--stream" => options.stream = true,
+            "--report-every" => {
+                options.report_every = iter
+                    .next()
+                    .and_then(|val| val.parse::<usize>().ok())
+                    .expect("--report-every expects a number");
+            },
+            _ => panic!("Invalid argument {:?}", arg),
+        }
+    }
+    options
+}
+
+pub fn load_from_file(file_path: &str) -> Vec<i64> {
+    let file = File::open(file_path).expect("file wasn't found.");
+    let reader = BufReader::new(file);
+
+    reader
+        .lines()
+        .map(|line| line.unwrap().parse::<i64>().unwrap())
+        .collect()
+}
+
+// Two neighbouring windows share all but their first & last reading,
+// so sum(i-window+1..=i) > sum(i-window..i) boils down to a[i] > a[i-window].
+pub fn analyse(numbers: &[i64], window: usize) -> SweepStats {
+    let mut stats = SweepStats::default();
+    let mut run = 0;
+    for i in window..numbers.len() {
+        let jump = numbers[i] - numbers[i - window];
+        if jump > 0 {
+            stats.increases.push(i);
+            run += 1;
+            stats.longest_increasing_run = stats.longest_increasing_run.max(run);
+        } else {
+            run = 0;
+        }
+
+        if stats.largest_jump.map_or(true, |(_, largest)| jump > largest) {
+            stats.largest_jump = Some((i, jump));
+        }
+    }
+
+    stats
+}
+
+// Counts increases without keeping the readings around: only the last `window`
+// readings live in a ring buffer. Running counts go to stderr every `report_every` readings.
+pub fn stream_increases(reader: impl BufRead, window: usize, report_every: usize) -> u64 {
+    let mut ring: Vec<i64> = vec![0; window];
+    let mut count: u64 = 0;
+    let mut readings: usize = 0;
+    for line in reader.lines() {
+        let line_str = line.unwrap();
+        let line_str = line_str.trim();
+        if line_str.is_empty() {
+            continue;
+        }
+
+        let number = line_str.parse::<i64>().unwrap();
+        let slot = readings % window;
+        if readings >= window && number > ring[slot] {
+            count += 1;
+        }
+        ring[slot] = number;
+        readings += 1;
+
+        if report_every > 0 && readings % report_every == 0 {
+            eprintln!("{:} readings: {:} increases", readings, count);
+        }
+    }
+
+    count
+}
+
+// the whole day01 command line, part 1 & 2 only differ in the default window
+pub fn run(default_window: usize) {
+    let args: Vec<String> = std::env::args().collect();
+    let options = parse_args(&args, default_window);
+    if options.stream {
+        let stdin = std::io::stdin();
+        println!("{:}", stream_increases(stdin.lock(), options.window, options.report_every));
+        return;
+    }
+
+    let numbers = load_from_file("data.in");
+    let stats = analyse(&numbers, options.window);
+
+    println!("{:}", stats.increases.len());
+    if options.stats {
+        println!("longest increasing run: {:}", stats.longest_increasing_run);
+        if let Some((idx, jump)) = stats.largest_jump {
+            println!("largest jump: {:} (window ending at {:})", jump, idx);
+        }
+        println!("increases at: {:?}", stats.increases);
+    }
+}
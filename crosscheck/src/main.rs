@@ -0,0 +1,134 @@
+// Both parts of a day started as copies of each other, so the code they still share can drift apart.
+// The solutions are pulled in as they are & their shared functions are run on the same inputs.
+// They are linted in their own crates, and only a few of their functions are used here.
+#[allow(dead_code, unexpected_cfgs, clippy::all)]
+#[path = "../../day16_part1/src/main.rs"]
+mod day16_part1;
+#[allow(dead_code, unexpected_cfgs, clippy::all)]
+#[path = "../../day16_part2/src/main.rs"]
+mod day16_part2;
+#[allow(dead_code, unexpected_cfgs, clippy::all)]
+#[path = "../../day20_part1/src/main.rs"]
+mod day20_part1;
+#[allow(dead_code, unexpected_cfgs, clippy::all)]
+#[path = "../../day20_part2/src/main.rs"]
+mod day20_part2;
+#[allow(dead_code, unexpected_cfgs, clippy::all)]
+#[path = "../../day23_part1/src/main.rs"]
+mod day23_part1;
+#[allow(dead_code, unexpected_cfgs, clippy::all)]
+#[path = "../../day23_part2/src/main.rs"]
+mod day23_part2;
+
+use std::fmt::Debug;
+
+// the examples from the puzzle text
+const DAY16_TRANSMISSIONS: [&str; 15] = [
+    "D2FE28",
+    "38006F45291200",
+    "EE00D40C823060",
+    "8A004A801A8002F478",
+    "620080001611562C8802118E34",
+    "C0015000016115A2E0802F182340",
+    "A0016C880162017C3686B18A3D4780",
+    "C200B40A82",
+    "04005AC33890",
+    "880086C3E88112",
+    "CE00C43D881120",
+    "D8005AC2A8F0",
+    "F600BC2D8F",
+    "9C005AC2F8F0",
+    "9C0141080250320F1802104A08",
+];
+
+// the parts have their own copies of the types, so results are compared through their Debug output
+fn check<T: Debug, U: Debug>(name: &str, part_1: T, part_2: U) {
+    let part_1 = std::format!("{:?}", part_1);
+    let part_2 = std::format!("{:?}", part_2);
+    if part_1 != part_2 {
+        panic!("{:} differs between the parts\npart 1: {:}\npart 2: {:}", name, part_1, part_2);
+    }
+}
+
+fn check_day16() {
+    for ch in "0123456789ABCDEF".chars() {
+        check(
+            &std::format!("day16 get_bit_vec_from_char('{:}')", ch),
+            day16_part1::get_bit_vec_from_char(ch),
+            day16_part2::get_bit_vec_from_char(ch),
+        );
+    }
+
+    let bit_vec = day16_part1::load_from_file("../day16_part1/data.in");
+    check("day16 load_from_file", &bit_vec, day16_part2::load_from_file("../day16_part1/data.in"));
+    check(
+        "day16 build_packet_tree",
+        day16_part1::build_packet_tree(&bit_vec, 0, bit_vec.len()),
+        day16_part2::build_packet_tree(&bit_vec, 0, bit_vec.len()),
+    );
+
+    for hex in DAY16_TRANSMISSIONS {
+        let mut bit_vec = bit_vec::BitVec::new();
+        for ch in hex.chars() {
+            bit_vec.append(&mut day16_part1::get_bit_vec_from_char(ch));
+        }
+        check(
+            &std::format!("day16 build_packet_tree({:})", hex),
+            day16_part1::build_packet_tree(&bit_vec, 0, bit_vec.len()),
+            day16_part2::build_packet_tree(&bit_vec, 0, bit_vec.len()),
+        );
+    }
+}
+
+fn check_day20() {
+    let input_1 = day20_part1::load_from_file("../day20_part1/data.in");
+    let input_2 = day20_part2::load_from_file("../day20_part1/data.in");
+    check("day20 load_from_file", &input_1, &input_2);
+
+    // including the pixels around the image, where the out of bounds value kicks in
+    let (rows, cols) = (input_1.matrix.rows as i64, input_1.matrix.cols as i64);
+    for oob_val in 0..=1 {
+        for r in -2..rows + 2 {
+            for c in -2..cols + 2 {
+                check(
+                    &std::format!("day20 get_pixel_index({:}, {:}, {:})", r, c, oob_val),
+                    day20_part1::get_pixel_index(&input_1.matrix, r, c, oob_val),
+                    day20_part2::get_pixel_index(&input_2.matrix, r, c, oob_val),
+                );
+            }
+        }
+    }
+
+    for oob_val in 0..=1 {
+        let mut o_mat_1 = day20_part1::Matrix { data: Vec::new(), rows: input_1.matrix.rows + 2, cols: input_1.matrix.cols + 2 };
+        o_mat_1.data.resize(o_mat_1.rows * o_mat_1.cols, false);
+        let mut o_mat_2 = day20_part2::Matrix { data: Vec::new(), rows: input_2.matrix.rows + 2, cols: input_2.matrix.cols + 2 };
+        o_mat_2.data.resize(o_mat_2.rows * o_mat_2.cols, false);
+
+        day20_part1::step(&input_1.matrix, &mut o_mat_1, &input_1.lookup, oob_val);
+        day20_part2::step(&input_2.matrix, &mut o_mat_2, &input_2.lookup, oob_val);
+        check(&std::format!("day20 step({:})", oob_val), &o_mat_1, &o_mat_2);
+        check(
+            &std::format!("day20 count_lit_values({:})", oob_val),
+            day20_part1::count_lit_values(&o_mat_1),
+            day20_part2::count_lit_values(&o_mat_2),
+        );
+    }
+}
+
+// part 2 unfolds the diagram into 4 rows, its loader has to keep reading the 2 rows of part 1 the same way
+fn check_day23() {
+    check(
+        "day23 load_from_file",
+        day23_part1::load_from_file("../day23_part1/data.in"),
+        day23_part2::load_from_file("../day23_part1/data.in"),
+    );
+}
+
+fn main() {
+    let checks: [(&str, fn()); 3] = [("day16", check_day16), ("day20", check_day20), ("day23", check_day23)];
+    for (day, run) in checks {
+        run();
+        println!("{:} ok", day);
+    }
+}
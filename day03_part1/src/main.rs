@@ -1,45 +1,71 @@
 use std::fs::File;
 use std::io::BufReader;
 use std::io::BufRead;
+use bit_vec::BitVec;
+use num_bigint::BigUint;
 
-fn load_from_file(file_path: &str) -> Vec<String> {
+fn load_from_file(file_path: &str) -> Vec<BitVec> {
     let file = File::open(file_path).expect("file wasn't found.");
     let reader = BufReader::new(file);
 
-    reader.lines().map(|line| line.unwrap()).collect()
+    let lines: Vec<BitVec> = reader
+        .lines()
+        .map(|line| line.unwrap())
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| parse_bits(line.trim()))
+        .collect();
+
+    let width = lines[0].len();
+    if let Some(line) = lines.iter().find(|line| line.len() != width) {
+        panic!("expected {:} bits, got {:}", width, to_binary_string(line));
+    }
+    lines
+}
+
+fn parse_bits(line: &str) -> BitVec {
+    line.chars()
+        .map(|ch| match ch {
+            '0' => false,
+            '1' => true,
+            _ => panic!("invalid bit: '{:}'", ch),
+        })
+        .collect()
+}
+
+fn to_binary_string(bits: &BitVec) -> String {
+    bits.iter().map(|bit| if bit { '1' } else { '0' }).collect()
+}
+
+fn to_biguint(bits: &BitVec) -> BigUint {
+    bits.iter().fold(BigUint::from(0u32), |acc, bit| (acc << 1u32) + bit as u32)
 }
 
-fn get_msb_value(lines: &Vec<String>) -> usize {
+fn get_msb_value(lines: &[BitVec]) -> BitVec {
     let line_len = lines[0].len();
     let mut bit_1_counts: Vec<usize> = vec![0; line_len];
 
     for line in lines {
-        let line_bytes = line.as_bytes();
-        for i in 0..line_len {
-            if line_bytes[i] == "1".as_bytes()[0] {
-                bit_1_counts[i] += 1;
-            }
+        for (count, bit) in bit_1_counts.iter_mut().zip(line.iter()) {
+            *count += bit as usize;
         }
     }
-    
-    let half_lines: usize = lines.len() / 2;
-    let mut result: usize = 0;
-    for bit_1_count in bit_1_counts {
-        result <<= 1;
-        result |= (bit_1_count > half_lines) as usize;
-    }
-
-    result
-}
 
-fn negate_bits_with_len(val: usize, len: u32) -> usize {
-    let shift_len = usize::BITS - len;
-   (!val << shift_len) >> shift_len
+    let half_lines: usize = lines.len() / 2;
+    bit_1_counts.iter().map(|&bit_1_count| bit_1_count > half_lines).collect()
 }
 
 fn main() {
+    let verbose = std::env::args().any(|arg| arg == "--verbose");
     let lines = load_from_file("data.in");
-    let msb_value: usize = get_msb_value(&lines);
-    let lsb_value: usize = negate_bits_with_len(msb_value, lines[0].len() as u32);
-    println!("{:}", lsb_value * msb_value);
+    let msb_value = get_msb_value(&lines);
+    let mut lsb_value = msb_value.clone();
+    lsb_value.negate();
+
+    let gamma = to_biguint(&msb_value);
+    let epsilon = to_biguint(&lsb_value);
+    println!("{:}", &gamma * &epsilon);
+    if verbose {
+        println!("gamma:   {:} ({:})", to_binary_string(&msb_value), gamma);
+        println!("epsilon: {:} ({:})", to_binary_string(&lsb_value), epsilon);
+    }
 }
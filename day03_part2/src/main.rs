@@ -1,73 +1,132 @@
 use std::fs::File;
 use std::io::BufReader;
 use std::io::BufRead;
+use bit_vec::BitVec;
+use num_bigint::BigUint;
 
-fn load_from_file(file_path: &str) -> Vec<String> {
+fn load_from_file(file_path: &str) -> Vec<BitVec> {
     let file = File::open(file_path).expect("file wasn't found.");
     let reader = BufReader::new(file);
 
-    reader.lines().map(|line| line.unwrap()).collect()
+    let lines: Vec<BitVec> = reader
+        .lines()
+        .map(|line| line.unwrap())
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| parse_bits(line.trim()))
+        .collect();
+
+    let width = lines[0].len();
+    if let Some(line) = lines.iter().find(|line| line.len() != width) {
+        panic!("expected {:} bits, got {:}", width, to_binary_string(line));
+    }
+    lines
 }
 
-fn get_ogr(lines: &Vec<String>) -> String {
-    let line_len = lines[0].len();
-    let mut filtered_lines: Vec<&String> = lines.iter().collect();
-    for i in 0..line_len {
-        let f_line_len = filtered_lines.len();
-        if f_line_len == 1 {
-            return filtered_lines[0].to_string();
-        }
-        
-        let mut set_bit_count = 0;
-        for f_line in &filtered_lines {
-            let line_bytes = f_line.as_bytes();
-            if line_bytes[i] == "1".as_bytes()[0] {
-                set_bit_count += 1;
-            }
-        }
-        
-        if set_bit_count >= (f_line_len - set_bit_count) {
-            filtered_lines = filtered_lines.into_iter().filter(|line| line.as_bytes()[i] == "1".as_bytes()[0]).collect();
-        } else {
-            filtered_lines = filtered_lines.into_iter().filter(|line| line.as_bytes()[i] == "0".as_bytes()[0]).collect();
+fn parse_bits(line: &str) -> BitVec {
+    line.chars()
+        .map(|ch| match ch {
+            '0' => false,
+            '1' => true,
+            _ => panic!("invalid bit: '{:}'", ch),
+        })
+        .collect()
+}
+
+fn to_binary_string(bits: &BitVec) -> String {
+    bits.iter().map(|bit| if bit { '1' } else { '0' }).collect()
+}
+
+fn to_biguint(bits: &BitVec) -> BigUint {
+    bits.iter().fold(BigUint::from(0u32), |acc, bit| (acc << 1u32) + bit as u32)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Rating {
+    // oxygen generator: keep the lines with the most common bit
+    MostCommon,
+    // CO2 scrubber: keep the lines with the least common bit
+    LeastCommon,
+}
+
+// which bit to keep when both are equally common
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TieBreak {
+    // 1 for the oxygen generator, 0 for the CO2 scrubber
+    Puzzle,
+    Ones,
+    Zeros,
+}
+
+impl TieBreak {
+    fn keep_ones(&self, rating: Rating) -> bool {
+        match self {
+            TieBreak::Puzzle => rating == Rating::MostCommon,
+            TieBreak::Ones => true,
+            TieBreak::Zeros => false,
         }
     }
+}
 
-    filtered_lines[0].to_string()
+fn parse_tie_break(val: Option<&str>) -> TieBreak {
+    match val {
+        Some("puzzle") => TieBreak::Puzzle,
+        Some("ones") => TieBreak::Ones,
+        Some("zeros") => TieBreak::Zeros,
+        val => panic!("--tie expects puzzle, ones or zeros, got {:?}", val),
+    }
 }
 
-fn get_csr(lines: &Vec<String>) -> String {
-    let line_len = lines[0].len();
-    let mut filtered_lines: Vec<&String> = lines.iter().collect();
+// Bisects the sorted lines instead of filtering copies of them. All lines in
+// sorted[lo..hi] share their first i bits, so the ones with bit i unset come first
+// and a binary search finds where the lines with bit i set start.
+fn find_rating(sorted: &[BitVec], rating: Rating, tie: TieBreak) -> &BitVec {
+    let line_len = sorted[0].len();
+    let mut lo = 0;
+    let mut hi = sorted.len();
     for i in 0..line_len {
-        let f_line_len = filtered_lines.len();
-        if f_line_len == 1 {
-            return filtered_lines[0].to_string();
-        }
-        
-        let mut unset_bit_count = 0;
-        for f_line in &filtered_lines {
-            let line_bytes = f_line.as_bytes();
-            if line_bytes[i] == "0".as_bytes()[0] {
-                unset_bit_count += 1;
-            }
+        if hi - lo == 1 {
+            break;
         }
-        
-        if unset_bit_count <= (f_line_len - unset_bit_count) {
-            filtered_lines = filtered_lines.into_iter().filter(|line| line.as_bytes()[i] == "0".as_bytes()[0]).collect();
+
+        let split = lo + sorted[lo..hi].partition_point(|line| !line[i]);
+        let unset_bit_count = split - lo;
+        let set_bit_count = hi - split;
+        let keep_ones = if unset_bit_count == 0 || set_bit_count == 0 {
+            set_bit_count > 0
+        } else if set_bit_count == unset_bit_count {
+            tie.keep_ones(rating)
+        } else {
+            (set_bit_count > unset_bit_count) == (rating == Rating::MostCommon)
+        };
+
+        if keep_ones {
+            lo = split;
         } else {
-            filtered_lines = filtered_lines.into_iter().filter(|line| line.as_bytes()[i] == "1".as_bytes()[0]).collect();
+            hi = split;
         }
     }
 
-    filtered_lines[0].to_string()
+    &sorted[lo]
 }
 
 fn main() {
-    let lines = load_from_file("data.in");
-    let ogr: String = get_ogr(&lines);
-    let csr: String = get_csr(&lines);
-    let ogr_val = isize::from_str_radix(&ogr, 2).unwrap();
-    let csr_val = isize::from_str_radix(&csr, 2).unwrap();
-    println!("{:}", ogr_val * csr_val);
+    let args: Vec<String> = std::env::args().collect();
+    let verbose = args.iter().any(|arg| arg == "--verbose");
+    let tie = args
+        .iter()
+        .position(|arg| arg == "--tie")
+        .map_or(TieBreak::Puzzle, |pos| parse_tie_break(args.get(pos + 1).map(String::as_str)));
+
+    let mut lines = load_from_file("data.in");
+    lines.sort_unstable();
+    let ogr = find_rating(&lines, Rating::MostCommon, tie);
+    let csr = find_rating(&lines, Rating::LeastCommon, tie);
+
+    let ogr_val = to_biguint(ogr);
+    let csr_val = to_biguint(csr);
+    println!("{:}", &ogr_val * &csr_val);
+    if verbose {
+        println!("oxygen generator rating: {:} ({:})", to_binary_string(ogr), ogr_val);
+        println!("CO2 scrubber rating:     {:} ({:})", to_binary_string(csr), csr_val);
+    }
 }
@@ -1,9 +1,19 @@
+use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fs::File;
 use std::io::BufReader;
 use std::io::BufRead;
 use regex::Regex;
 
+#[cfg(feature = "alloc-stats")]
+#[path = "../../common/alloc_stats.rs"]
+mod alloc_stats;
+#[path = "../../common/heatmap.rs"]
+mod heatmap;
+
+use heatmap::{HeatMap, Scale};
+
 #[derive(Debug, Clone)]
 struct Line {
    x1: i32,
@@ -12,7 +22,7 @@ struct Line {
    y2: i32,
 }
 
-#[derive(Hash, Eq, PartialEq, Debug)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
 struct Point {
     x: i32,
     y: i32,
@@ -38,36 +48,355 @@ fn load_from_file(file_path: &str) -> Vec<Line> {
     result
 }
 
-fn main() {
-    let raw_lines = load_from_file("data.in");
-    let lines: Vec<Line> = raw_lines
+// Every line is part of a family of parallel lines. Lines of the same family
+// share `key` and can only overlap along `pos`, lines of different families
+// cross in at most one point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum Family {
+    Horizontal,
+    Vertical,
+    Diagonal,
+    AntiDiagonal,
+}
+
+const FAMILIES: [Family; 4] = [Family::Horizontal, Family::Vertical, Family::Diagonal, Family::AntiDiagonal];
+
+impl Family {
+    fn of(line: &Line) -> Option<Family> {
+        let dx = line.x2 - line.x1;
+        let dy = line.y2 - line.y1;
+        if dy == 0 {
+            Some(Family::Horizontal)
+        } else if dx == 0 {
+            Some(Family::Vertical)
+        } else if dx == dy {
+            Some(Family::Diagonal)
+        } else if dx == -dy {
+            Some(Family::AntiDiagonal)
+        } else {
+            None
+        }
+    }
+
+    // (a, b) of the line equation a * x + b * y = key
+    fn coefs(&self) -> (i64, i64) {
+        match self {
+            Family::Horizontal => (0, 1),
+            Family::Vertical => (1, 0),
+            Family::Diagonal => (1, -1),
+            Family::AntiDiagonal => (1, 1),
+        }
+    }
+
+    fn key(&self, x: i64, y: i64) -> i64 {
+        let (a, b) = self.coefs();
+        a * x + b * y
+    }
+
+    fn pos(&self, x: i64, y: i64) -> i64 {
+        match self {
+            Family::Vertical => y,
+            _ => x,
+        }
+    }
+
+    fn point(&self, key: i64, pos: i64) -> (i64, i64) {
+        match self {
+            Family::Horizontal => (pos, key),
+            Family::Vertical => (key, pos),
+            Family::Diagonal => (pos, pos - key),
+            Family::AntiDiagonal => (pos, key - pos),
+        }
+    }
+}
+
+// merged runs of a family's key covered by at least one & at least two lines
+#[derive(Debug, Default)]
+struct Coverage {
+    union: Vec<(i64, i64)>,
+    overlap: Vec<(i64, i64)>,
+}
+
+fn get_coverage(intervals: &[(i64, i64)]) -> Coverage {
+    let mut events: Vec<(i64, i32)> = intervals
         .iter()
-        .filter(|line| line.x1 == line.x2 || 
-                line.y1 == line.y2 ||
-                (line.x2 - line.x1).abs() == (line.y2 - line.y1).abs())
-        .cloned()
+        .flat_map(|&(lo, hi)| [(lo, 1), (hi + 1, -1)])
         .collect();
+    events.sort_unstable();
 
-    let mut res: HashMap<Point, i32> = HashMap::new();
-    for line in &lines {
-        let dxx: i32 = line.x2 - line.x1;
-        let dyy: i32 = line.y2 - line.y1;
-        let diff: i32 = (if dxx != 0 { dxx } else { dyy }).abs();
-        let dx: i32 = (line.x2 - line.x1).clamp(-1, 1);
-        let dy: i32 = (line.y2 - line.y1).clamp(-1, 1);
-
-        for d in 0..=diff {
-            let p = Point { 
-                x: line.x1 + dx * d,
-                y: line.y1 + dy * d,
-            };
-            if let Some(&val) = res.get(&p) {
-                res.insert(p, val + 1);
-            } else {
-                res.insert(p, 1);
+    let mut coverage = Coverage::default();
+    let mut covered = 0;
+    let mut union_start = 0;
+    let mut overlap_start = 0;
+    for (pos, delta) in events {
+        let prev = covered;
+        covered += delta;
+        if prev == 0 && covered > 0 {
+            union_start = pos;
+        } else if prev > 0 && covered == 0 {
+            coverage.union.push((union_start, pos - 1));
+        }
+        if prev < 2 && covered >= 2 {
+            overlap_start = pos;
+        } else if prev >= 2 && covered < 2 {
+            coverage.overlap.push((overlap_start, pos - 1));
+        }
+    }
+
+    // touching runs come out as separate events at the same position, glue them back together
+    for runs in [&mut coverage.union, &mut coverage.overlap] {
+        let mut merged: Vec<(i64, i64)> = Vec::with_capacity(runs.len());
+        for &(lo, hi) in runs.iter() {
+            match merged.last_mut() {
+                Some(last) if last.1 + 1 >= lo => last.1 = last.1.max(hi),
+                _ => merged.push((lo, hi)),
             }
         }
+        *runs = merged;
     }
-    
-    println!("{:}", res.iter().filter(|&(_, v)| *v >= 2).count());
+
+    coverage
+}
+
+fn runs_contain(runs: &[(i64, i64)], pos: i64) -> bool {
+    let idx = runs.partition_point(|&(_, hi)| hi < pos);
+    idx < runs.len() && runs[idx].0 <= pos
+}
+
+fn intersect(f: Family, f_key: i64, g: Family, g_key: i64) -> Option<Point> {
+    let (a1, b1) = f.coefs();
+    let (a2, b2) = g.coefs();
+    let det = a1 * b2 - a2 * b1;
+    let x = f_key * b2 - g_key * b1;
+    let y = a1 * g_key - a2 * f_key;
+    if x % det != 0 || y % det != 0 {
+        return None;
+    }
+
+    Some(Point { x: (x / det) as i32, y: (y / det) as i32 })
+}
+
+// Lines of family f are fixed in p = f.key and span a range of q = g.key, lines of
+// family g the other way around, so finding their crossings is an orthogonal
+// segment intersection problem. Sweep over q keeping the active f lines ordered by p.
+fn get_crossings(coverages: &HashMap<(Family, i64), Coverage>, f: Family, g: Family, res: &mut HashSet<Point>) {
+    // (q, event order, p_lo, p_hi): 0 = f line starts, 1 = g line, 2 = f line ends
+    let mut events: Vec<(i64, u8, i64, i64)> = Vec::new();
+    for (&(family, key), coverage) in coverages {
+        for &(lo, hi) in &coverage.union {
+            let (x1, y1) = family.point(key, lo);
+            let (x2, y2) = family.point(key, hi);
+            if family == f {
+                let (q1, q2) = (g.key(x1, y1), g.key(x2, y2));
+                events.push((q1.min(q2), 0, key, key));
+                events.push((q1.max(q2), 2, key, key));
+            } else if family == g {
+                let (p1, p2) = (f.key(x1, y1), f.key(x2, y2));
+                events.push((key, 1, p1.min(p2), p1.max(p2)));
+            }
+        }
+    }
+    events.sort_unstable();
+
+    let mut active: BTreeMap<i64, u32> = BTreeMap::new();
+    for (q, order, p_lo, p_hi) in events {
+        match order {
+            0 => *active.entry(p_lo).or_insert(0) += 1,
+            1 => {
+                for (&p, _) in active.range(p_lo..=p_hi) {
+                    if let Some(point) = intersect(f, p, g, q) {
+                        res.insert(point);
+                    }
+                }
+            },
+            _ => {
+                let count = active.get_mut(&p_lo).unwrap();
+                *count -= 1;
+                if *count == 0 {
+                    active.remove(&p_lo);
+                }
+            },
+        }
+    }
+}
+
+fn count_overlaps(lines: &[Line]) -> usize {
+    let mut intervals: HashMap<(Family, i64), Vec<(i64, i64)>> = HashMap::new();
+    for line in lines {
+        let family = Family::of(line).expect("only horizontal, vertical and diagonal lines are supported");
+        let (x1, y1, x2, y2) = (line.x1 as i64, line.y1 as i64, line.x2 as i64, line.y2 as i64);
+        let (pos1, pos2) = (family.pos(x1, y1), family.pos(x2, y2));
+        intervals
+            .entry((family, family.key(x1, y1)))
+            .or_default()
+            .push((pos1.min(pos2), pos1.max(pos2)));
+    }
+
+    let coverages: HashMap<(Family, i64), Coverage> = intervals
+        .iter()
+        .map(|(&group, intervals)| (group, get_coverage(intervals)))
+        .collect();
+
+    // points where lines of the same family overlap
+    let mut res: i64 = coverages
+        .values()
+        .flat_map(|coverage| coverage.overlap.iter())
+        .map(|&(lo, hi)| hi - lo + 1)
+        .sum();
+
+    let mut crossings = HashSet::new();
+    for (i, &f) in FAMILIES.iter().enumerate() {
+        for &g in &FAMILIES[i + 1..] {
+            get_crossings(&coverages, f, g, &mut crossings);
+        }
+    }
+
+    // a crossing is already counted once for every family overlapping at that point
+    for point in crossings {
+        let (x, y) = (point.x as i64, point.y as i64);
+        let counted = FAMILIES
+            .iter()
+            .filter(|family| {
+                coverages
+                    .get(&(**family, family.key(x, y)))
+                    .is_some_and(|coverage| runs_contain(&coverage.overlap, family.pos(x, y)))
+            })
+            .count() as i64;
+        res += if counted == 0 { 1 } else { 1 - counted };
+    }
+
+    res as usize
+}
+
+// how lines of any slope are turned into points
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Raster {
+    // every cell the line passes closest to
+    Bresenham,
+    // only the integer points exactly on the line
+    Lattice,
+}
+
+fn gcd(a: i32, b: i32) -> i32 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+fn for_each_point(line: &Line, raster: Raster, mut f: impl FnMut(Point)) {
+    let dx = line.x2 - line.x1;
+    let dy = line.y2 - line.y1;
+    match raster {
+        Raster::Lattice => {
+            let steps = gcd(dx.abs(), dy.abs());
+            if steps == 0 {
+                f(Point { x: line.x1, y: line.y1 });
+                return;
+            }
+            for i in 0..=steps {
+                f(Point { x: line.x1 + dx / steps * i, y: line.y1 + dy / steps * i });
+            }
+        },
+        Raster::Bresenham => {
+            let sx = dx.signum();
+            let sy = dy.signum();
+            let (dx, dy) = (dx.abs(), -dy.abs());
+            let mut err = dx + dy;
+            let (mut x, mut y) = (line.x1, line.y1);
+            loop {
+                f(Point { x, y });
+                if x == line.x2 && y == line.y2 {
+                    break;
+                }
+                let e2 = 2 * err;
+                if e2 >= dy {
+                    err += dy;
+                    x += sx;
+                }
+                if e2 <= dx {
+                    err += dx;
+                    y += sy;
+                }
+            }
+        },
+    }
+}
+
+// Plain per-point counting, used for lines the sweep in `count_overlaps` can't handle
+// & to check the sweep with --verify.
+fn count_rasterized_overlaps(lines: &[Line], raster: Raster) -> usize {
+    let mut res: HashMap<Point, i32> = HashMap::new();
+    for line in lines {
+        for_each_point(line, raster, |p| *res.entry(p).or_insert(0) += 1);
+    }
+
+    res.iter().filter(|&(_, v)| *v >= 2).count()
+}
+
+fn get_heatmap(lines: &[Line], raster: Raster) -> HeatMap {
+    let max_x = lines.iter().map(|line| line.x1.max(line.x2)).max().unwrap_or(0);
+    let max_y = lines.iter().map(|line| line.y1.max(line.y2)).max().unwrap_or(0);
+
+    let mut heatmap = HeatMap::new(max_x, max_y);
+    for line in lines {
+        for_each_point(line, raster, |p| heatmap.add(p.x, p.y));
+    }
+    heatmap
+}
+
+fn get_arg_value<'a>(args: &'a [String], name: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|arg| arg == name)
+        .map(|pos| args.get(pos + 1).map(String::as_str).unwrap_or_else(|| panic!("{:} expects a value", name)))
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    let raster = get_arg_value(&args, "--any-slope").map(|val| match val {
+        "bresenham" => Raster::Bresenham,
+        "lattice" => Raster::Lattice,
+        _ => panic!("--any-slope expects bresenham or lattice, got {:?}", val),
+    });
+    let scale = match get_arg_value(&args, "--scale") {
+        None | Some("linear") => Scale::Linear,
+        Some("log") => Scale::Log,
+        Some(val) => panic!("--scale expects linear or log, got {:?}", val),
+    };
+    let heatmap_path = get_arg_value(&args, "--heatmap");
+    let ascii = args.iter().any(|arg| arg == "--ascii");
+    let verify = args.iter().any(|arg| arg == "--verify");
+
+    let raw_lines = load_from_file("data.in");
+    let lines: Vec<Line> = match raster {
+        Some(_) => raw_lines,
+        None => raw_lines
+            .iter()
+            .filter(|line| line.x1 == line.x2 || 
+                    line.y1 == line.y2 ||
+                    (line.x2 - line.x1).abs() == (line.y2 - line.y1).abs())
+            .cloned()
+            .collect(),
+    };
+
+    if let Some(raster) = raster {
+        println!("{:}", count_rasterized_overlaps(&lines, raster));
+    } else {
+        let count = count_overlaps(&lines);
+        if verify {
+            assert_eq!(count, count_rasterized_overlaps(&lines, Raster::Lattice), "sweep differs from per-point counting");
+        }
+        println!("{:}", count);
+    }
+
+    if ascii || heatmap_path.is_some() {
+        // the exact points of horizontal, vertical & 45° lines are the same either way
+        let heatmap = get_heatmap(&lines, raster.unwrap_or(Raster::Lattice));
+        if ascii {
+            print!("{:}", heatmap.to_ascii());
+        }
+        if let Some(path) = heatmap_path {
+            heatmap.write_pgm(path, scale);
+        }
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    alloc_stats::report();
 }
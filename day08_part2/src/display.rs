@@ -0,0 +1,205 @@
+use std::fmt;
+use std::fs::File;
+use std::io::BufReader;
+use std::io::BufRead;
+
+// one bit per segment or wire, 'a' being the lowest
+pub type Mask = u16;
+
+pub const MAX_SEGMENTS: usize = Mask::BITS as usize;
+
+// wiring[wire] = segment the wire is connected to
+pub type Wiring = Vec<usize>;
+
+#[derive(Debug, Clone)]
+pub struct Display {
+    pub segments: usize,
+    pub digits: Vec<(char, Mask)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecodeError {
+    InvalidPattern(String),
+    // no wiring turns every pattern into a digit
+    Inconsistent,
+    // more than one wiring does
+    Ambiguous,
+    // an output pattern the wiring doesn't turn into a digit
+    UnknownOutput(String),
+    NotANumber(String),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodeError::InvalidPattern(pattern) => write!(f, "invalid pattern {:?}", pattern),
+            DecodeError::Inconsistent => write!(f, "no wiring matches the patterns"),
+            DecodeError::Ambiguous => write!(f, "more than one wiring matches the patterns"),
+            DecodeError::UnknownOutput(pattern) => write!(f, "output pattern {:?} matches no digit", pattern),
+            DecodeError::NotANumber(output) => write!(f, "output {:?} isn't a number", output),
+        }
+    }
+}
+
+pub fn parse_pattern(pattern: &str) -> Result<Mask, DecodeError> {
+    pattern.chars().try_fold(0, |mask: Mask, ch| {
+        let idx = (ch as usize).wrapping_sub('a' as usize);
+        if idx >= MAX_SEGMENTS || mask & (1 << idx) != 0 {
+            return Err(DecodeError::InvalidPattern(pattern.to_string()));
+        }
+        Ok(mask | 1 << idx)
+    })
+}
+
+impl Display {
+    pub fn seven_segment() -> Display {
+        let digits = ["abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg"];
+        Display {
+            segments: 7,
+            digits: digits
+                .iter()
+                .enumerate()
+                .map(|(digit, segments)| (char::from_digit(digit as u32, 10).unwrap(), parse_pattern(segments).unwrap()))
+                .collect(),
+        }
+    }
+
+    // one digit per line, e.g. "0: abcefg"
+    pub fn from_file(file_path: &str) -> Display {
+        let file = File::open(file_path).unwrap_or_else(|_| panic!("File not found: {:}", file_path));
+        let reader = BufReader::new(file);
+
+        let mut digits = Vec::new();
+        for line in reader.lines() {
+            let line_str = line.unwrap();
+            if line_str.trim().is_empty() {
+                continue;
+            }
+
+            let (digit, segments) = line_str.split_once(':').unwrap_or_else(|| panic!("Invalid display line {:?}", line_str));
+            let digit = digit.trim().chars().next().unwrap_or_else(|| panic!("Missing digit in {:?}", line_str));
+            let mask = parse_pattern(segments.trim()).unwrap_or_else(|err| panic!("{:}", err));
+            digits.push((digit, mask));
+        }
+
+        let all_segments = digits.iter().fold(0, |acc, &(_, mask)| acc | mask);
+        Display { segments: (Mask::BITS - all_segments.leading_zeros()) as usize, digits }
+    }
+
+    fn all_segments(&self) -> Mask {
+        ((1u32 << self.segments) - 1) as Mask
+    }
+
+    pub fn decode(&self, wiring: &Wiring, pattern: Mask) -> Option<char> {
+        let segments = apply_wiring(wiring, pattern);
+        self.digits.iter().find(|&&(_, mask)| mask == segments).map(|&(digit, _)| digit)
+    }
+}
+
+pub fn apply_wiring(wiring: &Wiring, pattern: Mask) -> Mask {
+    wiring
+        .iter()
+        .enumerate()
+        .filter(|&(wire, _)| pattern & (1 << wire) != 0)
+        .fold(0, |acc, (_, &segment)| acc | 1 << segment)
+}
+
+// collects the wirings matching `patterns`, giving up once there's a second one
+struct Search<'a> {
+    display: &'a Display,
+    patterns: Vec<Mask>,
+    solutions: Vec<Wiring>,
+}
+
+impl Search<'_> {
+    fn fits(&self, candidates: &[Mask], pattern: Mask, digit: Mask) -> bool {
+        pattern.count_ones() == digit.count_ones() &&
+        candidates.iter().enumerate().all(|(wire, &segments)| {
+            let allowed = if pattern & (1 << wire) != 0 { digit } else { !digit };
+            segments & allowed != 0
+        })
+    }
+
+    // Assigns patterns to digits, most constrained pattern first. Every assignment
+    // narrows the wires of the pattern down to the digit's segments & all other wires
+    // to the remaining ones.
+    fn assign(&mut self, candidates: &[Mask], assigned: &mut [bool], used_digits: &mut [bool]) {
+        if self.solutions.len() > 1 {
+            return;
+        }
+
+        let mut best: Option<(usize, Vec<usize>)> = None;
+        for (idx, &pattern) in self.patterns.iter().enumerate() {
+            if assigned[idx] {
+                continue;
+            }
+            let options: Vec<usize> = (0..self.display.digits.len())
+                .filter(|&digit| !used_digits[digit] && self.fits(candidates, pattern, self.display.digits[digit].1))
+                .collect();
+            if best.as_ref().map_or(true, |(_, best_options)| options.len() < best_options.len()) {
+                best = Some((idx, options));
+            }
+        }
+
+        let (idx, options) = match best {
+            Some(best) => best,
+            None => return self.match_wires(candidates, 0, 0, &mut vec![0; candidates.len()]),
+        };
+
+        let pattern = self.patterns[idx];
+        assigned[idx] = true;
+        for digit in options {
+            let digit_mask = self.display.digits[digit].1;
+            let narrowed: Vec<Mask> = candidates
+                .iter()
+                .enumerate()
+                .map(|(wire, &segments)| segments & if pattern & (1 << wire) != 0 { digit_mask } else { !digit_mask })
+                .collect();
+
+            used_digits[digit] = true;
+            self.assign(&narrowed, assigned, used_digits);
+            used_digits[digit] = false;
+        }
+        assigned[idx] = false;
+    }
+
+    // enumerates the one-to-one wirings left over by the candidates
+    fn match_wires(&mut self, candidates: &[Mask], wire: usize, used: Mask, wiring: &mut Wiring) {
+        if self.solutions.len() > 1 {
+            return;
+        }
+        if wire == candidates.len() {
+            self.solutions.push(wiring.clone());
+            return;
+        }
+
+        for segment in 0..self.display.segments {
+            if candidates[wire] & !used & (1 << segment) != 0 {
+                wiring[wire] = segment;
+                self.match_wires(candidates, wire + 1, used | 1 << segment, wiring);
+            }
+        }
+    }
+}
+
+// Finds the only wiring that turns every pattern into one of the display's digits.
+pub fn solve(display: &Display, patterns: &[Mask]) -> Result<Wiring, DecodeError> {
+    let mut unique: Vec<Mask> = patterns.to_vec();
+    unique.sort_unstable();
+    unique.dedup();
+    if unique.iter().any(|&pattern| pattern & !display.all_segments() != 0) {
+        return Err(DecodeError::Inconsistent);
+    }
+
+    let mut search = Search { display, patterns: unique, solutions: Vec::new() };
+    let candidates = vec![display.all_segments(); display.segments];
+    let mut assigned = vec![false; search.patterns.len()];
+    let mut used_digits = vec![false; display.digits.len()];
+    search.assign(&candidates, &mut assigned, &mut used_digits);
+
+    match search.solutions.len() {
+        0 => Err(DecodeError::Inconsistent),
+        1 => Ok(search.solutions.pop().unwrap()),
+        _ => Err(DecodeError::Ambiguous),
+    }
+}
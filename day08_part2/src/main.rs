@@ -1,10 +1,12 @@
-use std::collections::HashMap;
-use std::collections::HashSet;
 use std::fs::File;
 use std::io::BufReader;
 use std::io::BufRead;
 use regex::Regex;
 
+mod display;
+
+use display::{parse_pattern, solve, DecodeError, Display, Mask, Wiring};
+
 #[derive(Debug)]
 struct Signal {
     input: Vec<String>,
@@ -12,7 +14,7 @@ struct Signal {
 }
 
 fn load_from_file(file_path: &str) -> Vec<Signal> {
-    let file = File::open(file_path).expect(std::format!("File not found: {:}", file_path).as_str());
+    let file = File::open(file_path).unwrap_or_else(|_| panic!("File not found: {:}", file_path));
     let reader = BufReader::new(file);
     
     let re = Regex::new(r"^(.*) \| (.*)$").unwrap();
@@ -29,153 +31,174 @@ fn load_from_file(file_path: &str) -> Vec<Signal> {
     result
 }
 
-fn get_codes(signal: &Signal) -> Vec<String> {
-    let mut codes = Vec::new();
-    codes.extend(signal.input.iter().cloned());
-    codes.extend(signal.output.iter().cloned());
-    codes
+const NO_DIGIT: u8 = u8::MAX;
+
+// segment -> the digits it is lit in, one bit per digit, sorted
+fn get_segment_signatures(display: &Display) -> Vec<Mask> {
+    let mut signatures: Vec<Mask> = (0..display.segments)
+        .map(|segment| {
+            display.digits
+                .iter()
+                .enumerate()
+                .filter(|&(_, &(_, mask))| mask & (1 << segment) != 0)
+                .fold(0, |acc, (digit, _)| acc | 1 << digit)
+        })
+        .collect();
+    signatures.sort_unstable();
+    signatures
 }
 
-fn sort_codes(codes: &mut Vec<String>) {
-    for code in codes {
-        let mut l: Vec<char> = code.chars().collect();
-        l.sort();
-        *code = l.into_iter().collect();
+// Fast path for the standard display. Digits with the same number of segments differ in
+// how many segments they share with 1 & 4 (see the notes at the bottom), which takes a
+// few bit operations per pattern. Returns the pattern -> digit lookup, or None if the
+// patterns don't look like the ten digits of an intact display.
+fn get_seven_segment_lookup(input: &[Mask], signatures: &[Mask]) -> Option<[u8; 128]> {
+    let one = *input.iter().find(|code| code.count_ones() == 2)?;
+    let four = *input.iter().find(|code| code.count_ones() == 4)?;
+
+    let mut lookup = [NO_DIGIT; 128];
+    let mut seen: Mask = 0;
+    for &code in input {
+        let num: u8 = match (code.count_ones(), (code & one).count_ones(), (code & four).count_ones()) {
+            (2, _, _) => 1,
+            (3, _, _) => 7,
+            (4, _, _) => 4,
+            (7, _, _) => 8,
+            (5, 2, _) => 3,
+            (5, _, 2) => 2,
+            (5, _, 3) => 5,
+            (6, 1, _) => 6,
+            (6, _, 4) => 9,
+            (6, _, 3) => 0,
+            _ => return None,
+        };
+        if code as usize >= lookup.len() || seen & (1 << num) != 0 {
+            return None;
+        }
+        seen |= 1 << num;
+        lookup[code as usize] = num;
+    }
+    if seen != (1 << 10) - 1 {
+        return None;
     }
-}
-
-fn contains_code(src_code: &String, code: &String) -> bool {
-    code.chars().all(|char| src_code.contains(char))
-}
 
-fn add_base_codes(num_to_code: &mut HashMap::<i32, String>, codes: &Vec<String>) {
-    for code in codes {
-        match code.len() {
-            2 => { num_to_code.insert(1, code.clone()); () },
-            3 => { num_to_code.insert(7, code.clone()); () },
-            4 => { num_to_code.insert(4, code.clone()); () },
-            7 => { num_to_code.insert(8, code.clone()); () },
-            _ => (),
+    // a wiring exists iff every wire is lit in the same digits as some segment
+    let mut wire_signatures: Vec<Mask> = vec![0; signatures.len()];
+    for &code in input {
+        for (wire, signature) in wire_signatures.iter_mut().enumerate() {
+            if code & (1 << wire) != 0 {
+                *signature |= 1 << lookup[code as usize];
+            }
         }
     }
+    wire_signatures.sort_unstable();
+    if wire_signatures != signatures {
+        return None;
+    }
+
+    Some(lookup)
 }
 
-fn add_len_5_codes(num_to_code: &mut HashMap::<i32, String>, codes: &Vec<String>) {
-    let len_6_codes = codes
-        .iter()
-        .filter(|code| code.len() == 6)
-        .cloned()
-        .collect::<HashSet<String>>()
-        .into_iter()
-        .collect::<Vec<String>>();
-    
-    codes
-        .iter()
-        .filter(|code| code.len() == 5)
-        .for_each(|code| {
-            if !len_6_codes.iter().any(|l6code| contains_code(l6code, code)) {
-                num_to_code.insert(2, code.clone());
-            } else if contains_code(code, &num_to_code[&7]) {
-                num_to_code.insert(3, code.clone());
-            } else {
-                num_to_code.insert(5, code.clone());
-            }
-        });
+#[derive(Debug)]
+struct Decoded {
+    // only known when the full search ran
+    wiring: Option<Wiring>,
+    value: u64,
 }
 
-fn add_len_6_codes(num_to_code: &mut HashMap::<i32, String>, codes: &Vec<String>) {
-    codes
-        .iter()
-        .filter(|code| code.len() == 6)
-        .for_each(|code| {
-            if contains_code(code, &num_to_code[&3]) {
-                num_to_code.insert(9, code.clone());
-            } else if contains_code(code, &num_to_code[&5]) {
-                num_to_code.insert(6, code.clone());
-            } else {
-                num_to_code.insert(0, code.clone());
+fn decode_signal(display: &Display, signatures: Option<&[Mask]>, signal: &Signal) -> Result<Decoded, DecodeError> {
+    let input = signal.input.iter().map(|code| parse_pattern(code)).collect::<Result<Vec<Mask>, DecodeError>>()?;
+    let output = signal.output.iter().map(|code| parse_pattern(code)).collect::<Result<Vec<Mask>, DecodeError>>()?;
+
+    if let Some(lookup) = signatures.and_then(|signatures| get_seven_segment_lookup(&input, signatures)) {
+        let value = output.iter().try_fold(0u64, |acc, &code| {
+            match lookup.get(code as usize) {
+                Some(&num) if num != NO_DIGIT => Some(acc * 10 + num as u64),
+                _ => None,
             }
         });
-}
+        if let Some(value) = value {
+            return Ok(Decoded { wiring: None, value });
+        }
+    }
 
-fn get_output_str(num_to_code: &HashMap::<i32, String>, codes: &Vec<String>) -> String {
-    let code_to_num: HashMap<&String, &i32> = Vec::from_iter(num_to_code.iter())
-        .iter()
-        .clone()
-        .map(|(k, v)| (v.clone(), k.clone()))
-        .collect();
+    // Anything the fast path can't handle goes through the full search. The wiring comes
+    // from the input patterns so that corrupted outputs get noticed, the outputs only
+    // help out when the input alone leaves it ambiguous.
+    let wiring = match solve(display, &input) {
+        Err(DecodeError::Ambiguous) => {
+            let mut patterns = input.clone();
+            patterns.extend(output.iter());
+            solve(display, &patterns)?
+        },
+        res => res?,
+    };
+
+    let mut output_str = String::new();
+    for (code, &mask) in signal.output.iter().zip(output.iter()) {
+        output_str.push(display.decode(&wiring, mask).ok_or_else(|| DecodeError::UnknownOutput(code.clone()))?);
+    }
+    match output_str.parse::<u64>() {
+        Ok(value) => Ok(Decoded { wiring: Some(wiring), value }),
+        Err(_) => Err(DecodeError::NotANumber(output_str)),
+    }
+}
 
-    codes.iter().map(|code| code_to_num[code].to_string()).collect::<String>()
+// e.g. "abcdefg -> cfgabde", the segment each wire is connected to
+fn format_wiring(wiring: &Wiring) -> String {
+    let to_char = |idx: usize| (b'a' + idx as u8) as char;
+    let wires: String = (0..wiring.len()).map(to_char).collect();
+    let segments: String = wiring.iter().map(|&segment| to_char(segment)).collect();
+    std::format!("{:} -> {:}", wires, segments)
 }
 
 fn main() {
-    let mut signals = load_from_file("data.in");
+    let args: Vec<String> = std::env::args().collect();
+    let verbose = args.iter().any(|arg| arg == "--verbose");
+    let display = match args.iter().position(|arg| arg == "--display") {
+        Some(pos) => Display::from_file(args.get(pos + 1).expect("--display expects a file path")),
+        None => Display::seven_segment(),
+    };
+    // the fast path doesn't work out the wiring, which verbose mode reports
+    let signatures = match verbose || args.iter().any(|arg| arg == "--display") {
+        true => None,
+        false => Some(get_segment_signatures(&display)),
+    };
+
+    let signals = load_from_file("data.in");
     let mut accumulator = 0;
     
-    for signal in &mut signals {
-        sort_codes(&mut signal.input);
-        sort_codes(&mut signal.output);
-        let codes = get_codes(&signal);
-        
-        let mut num_to_code = HashMap::<i32, String>::new();
-        add_base_codes(&mut num_to_code, &codes);
-        add_len_5_codes(&mut num_to_code, &codes);
-        add_len_6_codes(&mut num_to_code, &codes);
-
-        let output_str = get_output_str(&num_to_code, &signal.output);
-        accumulator += output_str.parse::<i32>().unwrap();
+    for (idx, signal) in signals.iter().enumerate() {
+        match decode_signal(&display, signatures.as_deref(), signal) {
+            Ok(decoded) => {
+                if let (true, Some(wiring)) = (verbose, &decoded.wiring) {
+                    println!("entry {:}: wiring {:}, output {:0width$}", idx + 1, format_wiring(wiring), decoded.value, width = signal.output.len());
+                }
+                accumulator += decoded.value;
+            },
+            Err(err) => eprintln!("entry {:}: {:}", idx + 1, err),
+        }
     }
     
     println!("{:}", accumulator);
 }
 
 /*
- num: is in | is not in
-   1: 0 1 3 4 7 8 9 | 2 5 6
-   2: 2 8 | 0 1 3 4 5 6 7 9
-   3: 3 8 9 | 0 2 3 4 5 6 7 
-   4: 4 8 9 | 0 1 2 3 5 6 7 
-   5: 5 6 8 | 0 1 2 3 4 7 9
-   6: 6 8 | 0 1 2 3 4 5 7 9
-   7: 0 3 7 8 9 | 1 2 4 5 6
-   8: 8 | 0 1 2 3 4 5 6 7 9
-   9: 8 9 | 0 1 2 3 4 5 6 7
-   0: 0 8 | 1 2 3 4 5 6 7 9
-
- num: contains | does not contain
-   1: 1 | 2 3 4 5 6 7 8 9 0
-   2: 2 | 1 3 4 5 6 7 8 9 0
-   3: 1 3 7 | 2 4 5 6 8 9 0
-   4: 1 4 | 2 3 5 6 7 8 9 0
-   5: 5 | 1 2 3 5 6 7 8 9 0
-   6: 5 6 | 1 2 3 4 7 8 9 0
-   7: 1 7 | 2 3 4 5 6 8 9 0
-   8: 1 2 3 4 5 6 7 8 9 0 | 
-   9: 1 3 4 5 7 9 | 2 6 8 0
-   0: 1 7 | 2 3 4 5 6 8 0 9 
-
-2: 1
-3: 7
-4: 4
-5: 2 3 5 
-6: 6 9 0
-7: 8
-
-1st pass
-1 cf
-7 acf
-4 bcdf
-8 abcdefg
-
-2nd pass
-5: 2 3 5 
-2 acdeg <- is not in any 6-len num
-3 acdfg <- contains 7
-5 abdfg <- otherwise 
-
-3rd pass
-6: 6 9 0
-6 abdefg <- contains 5 (& does not contain 3)
-9 abcdfg <- contains 3
-0 abcefg <- contains 7 (& does not contain 3)
+ segments each digit shares with 1 (cf) & 4 (bcdf), used by get_seven_segment_lookup
+
+ num  segments  len  & 1  & 4
+   1  cf          2    2    2
+   7  acf         3    2    2
+   4  bcdf        4    2    4
+   8  abcdefg     7    2    4
+   2  acdeg       5    1    2
+   3  acdfg       5    2    3
+   5  abdfg       5    1    3
+   0  abcefg      6    2    3
+   6  abdefg      6    1    3
+   9  abcdfg      6    2    4
+
+ the length alone settles 1, 7, 4 & 8
+ len 5: 3 is the only one containing all of 1, 2 & 5 share 2 & 3 segments with 4
+ len 6: 6 is the only one missing part of 1, 9 & 0 share 4 & 3 segments with 4
  */
@@ -9,7 +9,7 @@ struct Map {
 }
 
 fn load_from_file(file_path: &str) -> Map {
-    let file = File::open(file_path).expect(std::format!("File not found: {:}", file_path).as_str());
+    let file = File::open(file_path).unwrap_or_else(|_| panic!("File not found: {:}", file_path));
     let reader = BufReader::new(file);
     
     let mut data = Vec::new();
@@ -24,43 +24,102 @@ fn load_from_file(file_path: &str) -> Map {
     Map { data, len }
 }
 
-fn get_basin_product(map: &mut Map) -> i32 {
+#[derive(Debug)]
+struct Basin {
+    size: usize,
+    // (row, col) of its lowest cell
+    low_point: (usize, usize),
+    low_height: i32,
+}
+
+#[derive(Debug)]
+struct Basins {
+    // basin index of every cell, None for the 9s separating them
+    labels: Vec<Option<usize>>,
+    basins: Vec<Basin>,
+}
+
+// Labels the connected areas of non-9 cells with an explicit stack instead of recursion,
+// so the map size isn't limited by the call stack and the map itself stays untouched.
+fn label_basins(map: &Map) -> Basins {
     let rows = map.data.len() / map.len;
     let cols = map.len;
-    
-    let mut res = Vec::new();
-    for r in 0..rows {
-        for c in 0..cols {
-            let basin_size = flood_fill(map, r, c);
-            if basin_size > 0 {
-                res.push(basin_size);
+
+    let mut labels: Vec<Option<usize>> = vec![None; map.data.len()];
+    let mut basins = Vec::new();
+    let mut stack = Vec::new();
+    for start in 0..map.data.len() {
+        if map.data[start] == 9 || labels[start].is_some() {
+            continue;
+        }
+
+        let label = basins.len();
+        let mut basin = Basin { size: 0, low_point: (start / cols, start % cols), low_height: map.data[start] };
+        labels[start] = Some(label);
+        stack.push(start);
+        while let Some(idx) = stack.pop() {
+            let (r, c) = (idx / cols, idx % cols);
+            basin.size += 1;
+            if map.data[idx] < basin.low_height {
+                basin.low_height = map.data[idx];
+                basin.low_point = (r, c);
+            }
+
+            let neighbours = [
+                (r > 0).then(|| idx - cols),
+                (r + 1 < rows).then(|| idx + cols),
+                (c > 0).then(|| idx - 1),
+                (c + 1 < cols).then(|| idx + 1),
+            ];
+            for next in neighbours.into_iter().flatten() {
+                if map.data[next] != 9 && labels[next].is_none() {
+                    labels[next] = Some(label);
+                    stack.push(next);
+                }
             }
         }
-    } 
-    
-    res.sort_unstable_by(|a, b| b.cmp(a));
-    res[0] * res[1] * res[2]
+        basins.push(basin);
+    }
+
+    Basins { labels, basins }
+}
+
+fn get_basin_product(basins: &Basins) -> usize {
+    let mut sizes: Vec<usize> = basins.basins.iter().map(|basin| basin.size).collect();
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    sizes.iter().take(3).product()
 }
 
-fn flood_fill(map: &mut Map, r: usize, c: usize) -> i32 {
-    if map.data[r * map.len + c] == 9 {
-        0
-    } else {
-        map.data[r * map.len + c] = 9;
-        
-        let rows = map.data.len() / map.len;
-        let cols = map.len;
-        
-        1 + 
-        if r == 0 { 0 } else { flood_fill(map, r - 1, c) } + 
-        if r >= (rows -1) { 0 } else { flood_fill(map, r + 1, c) } + 
-        if c == 0 { 0 } else { flood_fill(map, r, c - 1) } + 
-        if c >= (cols -1) { 0 } else { flood_fill(map, r, c + 1) }
+// one letter per basin, cycling through a..z, '.' for the 9s
+fn render_labels(basins: &Basins, cols: usize) -> String {
+    let mut res = String::with_capacity(basins.labels.len() + basins.labels.len() / cols);
+    for row in basins.labels.chunks(cols) {
+        for label in row {
+            res.push(match label {
+                Some(label) => (b'a' + (label % 26) as u8) as char,
+                None => '.',
+            });
+        }
+        res.push('\n');
     }
+    res
 }
 
 fn main() {
-    let mut map = load_from_file("data.in");
-    let basin_product = get_basin_product(&mut map);
-    println!("{:}", basin_product);
+    let args: Vec<String> = std::env::args().collect();
+    let show_basins = args.iter().any(|arg| arg == "--basins");
+    let show_labels = args.iter().any(|arg| arg == "--labels");
+    let map = load_from_file("data.in");
+    let basins = label_basins(&map);
+    println!("{:}", get_basin_product(&basins));
+
+    if show_basins {
+        for (label, basin) in basins.basins.iter().enumerate() {
+            println!("basin {:}: size {:}, low point {:?} at height {:}", label, basin.size, basin.low_point, basin.low_height);
+        }
+    }
+
+    if show_labels {
+        print!("{:}", render_labels(&basins, map.len));
+    }
 }
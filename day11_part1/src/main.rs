@@ -2,6 +2,11 @@ use std::fs::File;
 use std::io::BufReader;
 use std::io::BufRead;
 
+#[path = "../../common/animate.rs"]
+mod animate;
+
+use animate::Animation;
+
 #[derive(Debug)]
 struct Map {
     data: Vec<i32>,
@@ -10,7 +15,7 @@ struct Map {
 }
 
 fn load_from_file(file_path: &str) -> Map {
-    let file = File::open(file_path).expect(std::format!("File not found: {:}", file_path).as_str());
+    let file = File::open(file_path).unwrap_or_else(|_| panic!("File not found: {:}", file_path));
     let reader = BufReader::new(file);
     
     let mut data = Vec::new();
@@ -25,17 +30,19 @@ fn load_from_file(file_path: &str) -> Map {
     Map { rows: data.len() / cols, data, cols}
 }
 
-fn simulate_flashes(map: &mut Map) -> usize {
+fn simulate_flashes(map: &mut Map, mut on_step: impl FnMut(&Map, usize)) -> usize {
     let steps = 100;
     
     let mut flash_count: usize = 0;
-    for _ in 0..steps {
+    on_step(map, 0);
+    for step in 1..=steps {
         increase_energy_levels(map);
         while should_flash(map) {
             flash(map);
         }
         reset_flash(map);
         flash_count += count_flashes(map);
+        on_step(map, step);
     }
     
     flash_count
@@ -80,11 +87,11 @@ fn flash(map: &mut Map) {
         }
     }
     
-    for i in 0..map.data.len() {
-        if map.data[i] > 9 {
-            map.data[i] = -1;
+    for (cell, &next) in map.data.iter_mut().zip(&data_copy) {
+        if *cell > 9 {
+            *cell = -1;
         } else {
-            map.data[i] = data_copy[i];
+            *cell = next;
         }
     }
 }
@@ -101,8 +108,32 @@ fn count_flashes(map: &mut Map) -> usize {
     map.data.iter().filter(|&&val| val == 0).count()
 }
 
+// octopuses which just flashed are drawn in bold
+fn render_map(map: &Map) -> String {
+    let mut res = String::new();
+    for r in 0..map.rows {
+        for c in 0..map.cols {
+            let val = map.data[r * map.cols + c];
+            if val == 0 {
+                res.push_str("\x1b[1m0\x1b[0m");
+            } else {
+                res.push_str(&val.to_string());
+            }
+        }
+        res.push('\n');
+    }
+    res
+}
+
 fn main() {
+    let args: Vec<String> = std::env::args().collect();
     let mut map = load_from_file("data.in");
-    let score = simulate_flashes(&mut map);
+
+    let score = match Animation::from_args(&args) {
+        Some(animation) => simulate_flashes(&mut map, |map, step| {
+            animation.show(&std::format!("step {:}", step), &render_map(map));
+        }),
+        None => simulate_flashes(&mut map, |_, _| ()),
+    };
     println!("{:}", score);
 }
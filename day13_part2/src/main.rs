@@ -5,6 +5,11 @@ use std::io::BufRead;
 
 use regex::Regex;
 
+#[path = "../../common/animate.rs"]
+mod animate;
+
+use animate::Animation;
+
 #[derive(Hash, Eq, PartialEq, Debug)]
 struct Point {
     x: i32,
@@ -30,7 +35,7 @@ struct Instructions {
 }
 
 fn load_from_file(file_path: &str) -> Instructions {
-    let file = File::open(file_path).expect(std::format!("File not found: {:}", file_path).as_str());
+    let file = File::open(file_path).unwrap_or_else(|_| panic!("File not found: {:}", file_path));
     let reader = BufReader::new(file);
     
     let mut points: Vec<Point> = Vec::new();
@@ -64,42 +69,43 @@ fn load_from_file(file_path: &str) -> Instructions {
 }
 
 
-fn fold_dots(instructions: &mut Instructions) {
-    for fold in &instructions.folds {
+fn fold_dots(instructions: &mut Instructions, mut on_step: impl FnMut(&[Point], usize)) {
+    on_step(&instructions.points, 0);
+    for (step, fold) in instructions.folds.iter().enumerate() {
         for point in &mut instructions.points {
             match fold.dir {
                 FoldDir::X => if point.x >= fold.val { point.x = fold.val - (point.x - fold.val); },
                 FoldDir::Y => if point.y >= fold.val { point.y = fold.val - (point.y - fold.val); },
             }
         }
+        on_step(&instructions.points, step + 1);
     }
 }
 
-fn count_dots(instructions: &Instructions) -> i32 {
-    instructions.points.iter().collect::<HashSet<&Point>>().iter().count() as i32
-}
-
-fn fold_and_count_dots(instructions: &mut Instructions) -> i32 {
-    fold_dots(instructions);
-    count_dots(instructions)
-}
+fn render_dots(points: &[Point]) -> String {
+    let max_x = points.iter().map(|point| point.x).max().unwrap();
+    let max_y = points.iter().map(|point| point.y).max().unwrap();
 
-fn draw(instructions: &Instructions) {
-    let max_x = instructions.points.iter().map(|point| point.x).max().unwrap();
-    let max_y = instructions.points.iter().map(|point| point.y).max().unwrap();
-    
-
-    let set = instructions.points.iter().collect::<HashSet<&Point>>();
+    let set = points.iter().collect::<HashSet<&Point>>();
+    let mut res = String::new();
     for y in 0..=max_y {
         for x in 0..=max_x {
-            print!("{:}", if set.contains(&Point {x, y}) { '#' } else { '.' });
+            res.push(if set.contains(&Point {x, y}) { '#' } else { '.' });
         }
-        println!();
+        res.push('\n');
     }
+    res
 }
 
 fn main() {
+    let args: Vec<String> = std::env::args().collect();
     let mut instructions = load_from_file("data.in");
-    let count  = fold_and_count_dots(&mut instructions);
-    draw(&instructions);
+
+    match Animation::from_args(&args) {
+        Some(animation) => fold_dots(&mut instructions, |points, step| {
+            animation.show(&std::format!("fold {:}", step), &render_dots(points));
+        }),
+        None => fold_dots(&mut instructions, |_, _| ()),
+    }
+    print!("{:}", render_dots(&instructions.points));
 }
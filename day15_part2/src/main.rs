@@ -4,6 +4,10 @@ use std::fs::File;
 use std::io::BufReader;
 use std::io::BufRead;
 
+#[cfg(feature = "alloc-stats")]
+#[path = "../../common/alloc_stats.rs"]
+mod alloc_stats;
+
 #[derive(Debug)]
 struct Map {
     data: Vec<u32>,
@@ -138,4 +142,7 @@ fn search(map: &Map) -> Option<u32> {
 fn main() {
     let map = load_from_file("data.in");
     println!("{:}", search(&extend_map(&map)).unwrap());
+
+    #[cfg(feature = "alloc-stats")]
+    alloc_stats::report();
 }
@@ -3,7 +3,7 @@ use std::io::BufReader;
 use std::io::BufRead;
 use bit_vec::BitVec;
 
-fn load_from_file(file_path: &str) -> BitVec {
+pub fn load_from_file(file_path: &str) -> BitVec {
     let file = File::open(file_path).expect(std::format!("File not found: {:}", file_path).as_str());
     let reader = BufReader::new(file);
     
@@ -19,7 +19,7 @@ fn load_from_file(file_path: &str) -> BitVec {
     res
 }
 
-fn get_bit_vec_from_char(ch: char) -> BitVec {
+pub fn get_bit_vec_from_char(ch: char) -> BitVec {
     let bv = 
         match ch {
             '0' => BitVec::from_bytes(&[0]),
@@ -57,7 +57,7 @@ enum PacketData {
 }
 
 #[derive(Debug)]
-struct Packet {
+pub struct Packet {
     version: u8,
     type_id: u8,
     data: PacketData,
@@ -87,7 +87,7 @@ enum TraverseMode {
 }
 
 // returns root packet
-fn build_packet_tree(bit_vec: &BitVec, start_idx: usize, end_idx: usize) -> (usize, Packet) {
+pub fn build_packet_tree(bit_vec: &BitVec, start_idx: usize, end_idx: usize) -> (usize, Packet) {
     let mut read_mode = TraverseMode::ReadVersion(0);
     let mut packet = Packet { version: 0, type_id: 0, data: PacketData::None };
     let mut mini_buf = 0u16; 
@@ -3,7 +3,12 @@ use std::io::BufReader;
 use std::io::BufRead;
 use bit_vec::BitVec;
 
-fn load_from_file(file_path: &str) -> BitVec {
+#[path = "../../common/repl.rs"]
+mod repl;
+
+use repl::Command;
+
+pub fn load_from_file(file_path: &str) -> BitVec {
     let file = File::open(file_path).expect(std::format!("File not found: {:}", file_path).as_str());
     let reader = BufReader::new(file);
     
@@ -19,7 +24,7 @@ fn load_from_file(file_path: &str) -> BitVec {
     res
 }
 
-fn get_bit_vec_from_char(ch: char) -> BitVec {
+pub fn get_bit_vec_from_char(ch: char) -> BitVec {
     let bv = 
         match ch {
             '0' => BitVec::from_bytes(&[0]),
@@ -57,7 +62,7 @@ enum PacketData {
 }
 
 #[derive(Debug)]
-struct Packet {
+pub struct Packet {
     version: u8,
     type_id: u8,
     data: PacketData,
@@ -87,7 +92,7 @@ enum TraverseMode {
 }
 
 // returns root packet
-fn build_packet_tree(bit_vec: &BitVec, start_idx: usize, end_idx: usize) -> (usize, Packet) {
+pub fn build_packet_tree(bit_vec: &BitVec, start_idx: usize, end_idx: usize) -> (usize, Packet) {
     let mut read_mode = TraverseMode::ReadVersion(0);
     let mut packet = Packet { version: 0, type_id: 0, data: PacketData::None };
     let mut mini_buf = 0u16; 
@@ -260,9 +265,103 @@ fn get_eval(packet: &Packet) -> u64 {
     }
 }
 
+fn get_type_name(type_id: u8) -> &'static str {
+    match type_id {
+        0 => "sum",
+        1 => "product",
+        2 => "min",
+        3 => "max",
+        4 => "literal",
+        5 => "greater than",
+        6 => "less than",
+        7 => "equal to",
+        _ => "unknown",
+    }
+}
+
+// one packet per line, children indented below their operator
+fn print_packet_tree(packet: &Packet, path: &str) {
+    let depth = path.matches('.').count();
+    println!("{:}{:} v{:} {:} = {:}", "  ".repeat(depth), path, packet.version, get_type_name(packet.type_id), get_eval(packet));
+    if let PacketData::Operator(children) = &packet.data {
+        for (idx, child) in children.iter().enumerate() {
+            print_packet_tree(child, &std::format!("{:}.{:}", path, idx));
+        }
+    }
+}
+
+// `0.2.1` is the 2nd child of the 3rd child of the root packet
+fn find_packet<'a>(root: &'a Packet, path: &str) -> Result<&'a Packet, String> {
+    let mut packet = root;
+    for idx in path.split('.').skip(1) {
+        let idx = idx.parse::<usize>().map_err(|_| std::format!("invalid path {:?}", path))?;
+        packet = match &packet.data {
+            PacketData::Operator(children) => children.get(idx),
+            _ => None,
+        }
+        .ok_or_else(|| std::format!("no packet at {:?}", path))?;
+    }
+    Ok(packet)
+}
+
+fn decode_packet(bit_vec: &BitVec) -> Packet {
+    build_packet_tree(bit_vec, 0, bit_vec.len()).1
+}
+
+const REPL_COMMANDS: [Command<Packet>; 4] = [
+    Command {
+        name: "load",
+        usage: "load PATH   decode the transmission in another file",
+        run: |root, args| {
+            *root = decode_packet(&load_from_file(args.first().ok_or("missing path")?));
+            Ok(())
+        },
+    },
+    Command {
+        name: "decode",
+        usage: "decode HEX  decode a transmission given in hex, e.g. 9C0141080250320F1802104A08",
+        run: |root, args| {
+            let hex = args.first().ok_or("missing transmission")?;
+            if let Some(ch) = hex.chars().find(|ch| !ch.is_ascii_hexdigit() || ch.is_ascii_lowercase()) {
+                return Err(std::format!("invalid hex digit '{:}'", ch));
+            }
+            let mut bit_vec = BitVec::new();
+            for ch in hex.chars() {
+                bit_vec.append(&mut get_bit_vec_from_char(ch));
+            }
+            *root = decode_packet(&bit_vec);
+            Ok(())
+        },
+    },
+    Command {
+        name: "show",
+        usage: "show [0.N]  print the packet tree, or the subtree at a path",
+        run: |root, args| {
+            let path = args.first().copied().unwrap_or("0");
+            print_packet_tree(find_packet(root, path)?, path);
+            Ok(())
+        },
+    },
+    Command {
+        name: "eval",
+        usage: "eval [0.N]  value of the whole transmission, or of the packet at a path",
+        run: |root, args| {
+            println!("{:}", get_eval(find_packet(root, args.first().copied().unwrap_or("0"))?));
+            Ok(())
+        },
+    },
+];
+
 fn main() {
+    let args: Vec<String> = std::env::args().collect();
     let bit_vec = load_from_file("data.in");
     let root_packet = build_packet_tree(&bit_vec, 0, bit_vec.len());
+    if args.iter().any(|arg| arg == "--repl") {
+        let mut root = root_packet.1;
+        repl::run(&mut root, &REPL_COMMANDS);
+        return;
+    }
+
     let evaluated = get_eval(&root_packet.1);
     println!("{:}", evaluated);
 }
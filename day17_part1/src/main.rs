@@ -3,6 +3,15 @@ use std::io::BufReader;
 use std::io::BufRead;
 use regex::Regex;
 
+#[path = "../../common/animate.rs"]
+mod animate;
+
+use animate::Animation;
+
+// the trajectory is scaled down to fit the terminal
+const MAX_FRAME_COLS: i64 = 120;
+const MAX_FRAME_ROWS: i64 = 50;
+
 #[derive(Debug)]
 struct Area {
     x1: i64,
@@ -12,7 +21,7 @@ struct Area {
 }
 
 impl Area {
-    fn contains(self: &Self, point: &Point) -> bool {
+    fn contains(&self, point: &Point) -> bool {
         point.x >= self.x1 && point.x <= self.x2 && point.y >= self.y1 && point.y <= self.y2 
     }
 }
@@ -24,7 +33,7 @@ struct Point {
 }
 
 fn load_from_file(file_path: &str) -> Area {
-    let file = File::open(file_path).expect(std::format!("File not found: {:}", file_path).as_str());
+    let file = File::open(file_path).unwrap_or_else(|_| panic!("File not found: {:}", file_path));
     let mut reader = BufReader::new(file);
     let mut line = String::new();
     reader.read_line(&mut line).unwrap();
@@ -45,14 +54,15 @@ fn load_from_file(file_path: &str) -> Area {
     }
 }
 
-fn try_hit(start_velocity: &Point, area: &Area) -> Option<i64> {
+fn try_hit(start_velocity: &Point, area: &Area, mut on_step: impl FnMut(&Point)) -> Option<i64> {
     let mut pos = Point { x: 0, y: 0 };
-    let mut vel = start_velocity.clone();
+    let mut vel = *start_velocity;
     let mut max_y = 0;
     
     let min_y = std::cmp::min(area.y1, area.y2);
     while pos.y >= min_y {
         max_y = std::cmp::max(pos.y, max_y);
+        on_step(&pos);
         
         if area.contains(&pos) {
             return Some(max_y);
@@ -67,20 +77,70 @@ fn try_hit(start_velocity: &Point, area: &Area) -> Option<i64> {
     None
 }
 
-fn find_optimal_velocity(area: &Area) -> i64 {
+// returns the highest y reached & the first velocity reaching it
+fn find_optimal_velocity(area: &Area) -> (i64, Point) {
     let mut max_y = 0;
+    let mut best = Point { x: 0, y: 0 };
     for x in -2*area.x2..2*area.x2 {
         for y in (-2*area.y2.abs())..(2 * area.y2.abs()) {
-            if let Some(hit) = try_hit(&Point{ x, y }, &area) {
-                max_y = std::cmp::max(max_y, hit);
+            if let Some(hit) = try_hit(&Point{ x, y }, area, |_| ()) {
+                if hit > max_y {
+                    max_y = hit;
+                    best = Point { x, y };
+                }
             }
         }
     }
-    max_y
+    (max_y, best)
+}
+
+// 'S' for the launcher, 'T' for the target area, '#' for the probe's positions so far
+fn render_trajectory(path: &[Point], area: &Area) -> String {
+    let min_x = path.iter().map(|pos| pos.x).min().unwrap().min(area.x1).min(0);
+    let max_x = path.iter().map(|pos| pos.x).max().unwrap().max(area.x2).max(0);
+    let min_y = path.iter().map(|pos| pos.y).min().unwrap().min(area.y1).min(0);
+    let max_y = path.iter().map(|pos| pos.y).max().unwrap().max(area.y2).max(0);
+
+    let cols = (max_x - min_x + 1).min(MAX_FRAME_COLS);
+    let rows = (max_y - min_y + 1).min(MAX_FRAME_ROWS);
+    let to_cell = |pos: &Point| {
+        let c = (pos.x - min_x) * (cols - 1) / (max_x - min_x).max(1);
+        let r = (max_y - pos.y) * (rows - 1) / (max_y - min_y).max(1);
+        (r * cols + c) as usize
+    };
+
+    let mut cells = vec!['.'; (rows * cols) as usize];
+    let (top_left, bottom_right) = (to_cell(&Point { x: area.x1, y: area.y2 }), to_cell(&Point { x: area.x2, y: area.y1 }));
+    for r in top_left / cols as usize..=bottom_right / cols as usize {
+        for c in top_left % cols as usize..=bottom_right % cols as usize {
+            cells[r * cols as usize + c] = 'T';
+        }
+    }
+    for pos in path {
+        cells[to_cell(pos)] = '#';
+    }
+    cells[to_cell(&Point { x: 0, y: 0 })] = 'S';
+
+    let mut res = String::new();
+    for row in cells.chunks(cols as usize) {
+        res.extend(row);
+        res.push('\n');
+    }
+    res
 }
 
 fn main() {
+    let args: Vec<String> = std::env::args().collect();
     let area = load_from_file("data.in");
-    println!("{:}", find_optimal_velocity(&area));
+    let (max_y, best) = find_optimal_velocity(&area);
+
+    if let Some(animation) = Animation::from_args(&args) {
+        let mut path = Vec::new();
+        try_hit(&best, &area, |pos| {
+            path.push(*pos);
+            animation.show(&std::format!("velocity {:},{:} step {:}", best.x, best.y, path.len() - 1), &render_trajectory(&path, &area));
+        });
+    }
+    println!("{:}", max_y);
 }
 
@@ -6,6 +6,11 @@ use std::io::BufRead;
 
 use regex::Regex;
 
+#[path = "../../common/repl.rs"]
+mod repl;
+
+use repl::Command;
+
 type Point = [i64; 3];
 
 #[derive(Debug, Clone)]
@@ -201,8 +206,75 @@ fn count_beacons(scanners: &Vec<Scanner>) -> usize {
         
 }
 
+fn get_scanner(scanners: &[Scanner], args: &[&str], idx: usize) -> Result<usize, String> {
+    let scanner = args
+        .get(idx)
+        .and_then(|val| val.parse::<usize>().ok())
+        .ok_or("expected a scanner number")?;
+    if scanner >= scanners.len() {
+        return Err(std::format!("there are only {:} scanners", scanners.len()));
+    }
+    Ok(scanner)
+}
+
+const REPL_COMMANDS: [Command<Vec<Scanner>>; 4] = [
+    Command {
+        name: "load",
+        usage: "load PATH   read scanners from another file",
+        run: |scanners, args| {
+            *scanners = load_from_file(args.first().ok_or("missing path")?);
+            println!("{:} scanners", scanners.len());
+            Ok(())
+        },
+    },
+    Command {
+        name: "scanners",
+        usage: "scanners    number of beacons every scanner sees",
+        run: |scanners, _| {
+            for (idx, scanner) in scanners.iter().enumerate() {
+                println!("scanner {:}: {:} beacons", idx, scanner.beacons.len());
+            }
+            Ok(())
+        },
+    },
+    Command {
+        name: "offset",
+        usage: "offset I J  position of scanner J relative to scanner I, if they share 12 beacons",
+        run: |scanners, args| {
+            let (i, j) = (get_scanner(scanners, args, 0)?, get_scanner(scanners, args, 1)?);
+            let mut beacons_buf = scanners[j].beacons.clone();
+            for (rot_idx, rot) in get_rotations().iter().enumerate() {
+                rotate(&scanners[j].beacons, rot, &mut beacons_buf);
+                if let Some(offset) = get_scanner_offset(&scanners[i].beacons, &beacons_buf) {
+                    println!("{:?} with rotation {:} {:?}", offset, rot_idx, rot);
+                    return Ok(());
+                }
+            }
+            println!("scanners {:} & {:} don't overlap", i, j);
+            Ok(())
+        },
+    },
+    Command {
+        name: "count",
+        usage: "count       number of distinct beacons",
+        run: |scanners, _| {
+            let mut oriented = scanners.clone();
+            let scan_deps = get_scanner_dependencies_and_orient_scanners(&mut oriented);
+            translate_beacons(&mut oriented, &scan_deps);
+            println!("{:}", count_beacons(&oriented));
+            Ok(())
+        },
+    },
+];
+
 fn main() {
+    let args: Vec<String> = std::env::args().collect();
     let mut scanners = load_from_file("data.in");
+    if args.iter().any(|arg| arg == "--repl") {
+        repl::run(&mut scanners, &REPL_COMMANDS);
+        return;
+    }
+
     let scan_deps = get_scanner_dependencies_and_orient_scanners(&mut scanners);
     translate_beacons(&mut scanners, &scan_deps);
     println!("{:}", count_beacons(&scanners));
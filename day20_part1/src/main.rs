@@ -2,19 +2,19 @@ use std::fs::File;
 use std::io::BufReader;
 use std::io::BufRead;
 
-type Lookup = Vec<bool>;
+pub type Lookup = Vec<bool>;
 
 #[derive(Debug, Clone)]
-struct Matrix {
-    data: Vec<bool>,
-    rows: usize,
-    cols: usize,
+pub struct Matrix {
+    pub data: Vec<bool>,
+    pub rows: usize,
+    pub cols: usize,
 }
 
 #[derive(Debug)]
-struct Input {
-    matrix: Matrix,
-    lookup: Lookup,
+pub struct Input {
+    pub matrix: Matrix,
+    pub lookup: Lookup,
 }
 
 fn convert_line_to_bool_vec(line: &String) -> Vec<bool> {
@@ -25,7 +25,7 @@ fn convert_line_to_bool_vec(line: &String) -> Vec<bool> {
     }).collect::<Vec<bool>>()
 }
 
-fn load_from_file(file_path: &str) -> Input {
+pub fn load_from_file(file_path: &str) -> Input {
     let file = File::open(file_path).expect(std::format!("File not found: {:}", file_path).as_str());
     let mut reader = BufReader::new(file);
     let mut buf = String::new();
@@ -51,7 +51,7 @@ fn load_from_file(file_path: &str) -> Input {
     }
 }
 
-fn get_pixel_index(mat: &Matrix, r: i64, c: i64, oob_val: usize) -> usize {
+pub fn get_pixel_index(mat: &Matrix, r: i64, c: i64, oob_val: usize) -> usize {
     let mut val = 0;
     for rr in -1..=1 {
         for cc in -1..=1 {
@@ -74,7 +74,7 @@ fn get_pixel_index(mat: &Matrix, r: i64, c: i64, oob_val: usize) -> usize {
     val
 }
 
-fn step(i_mat: &Matrix, o_mat: &mut Matrix, lookup: &Lookup, oob_val: usize) {
+pub fn step(i_mat: &Matrix, o_mat: &mut Matrix, lookup: &Lookup, oob_val: usize) {
     for r in 0..o_mat.rows as i64 {
         for c in 0..o_mat.cols as i64 {
             let rr = r - 1;
@@ -84,7 +84,7 @@ fn step(i_mat: &Matrix, o_mat: &mut Matrix, lookup: &Lookup, oob_val: usize) {
     }
 }
 
-fn count_lit_values(mat: &Matrix) -> usize {
+pub fn count_lit_values(mat: &Matrix) -> usize {
     mat.data.iter().filter(|val| **val).count()
 }
 
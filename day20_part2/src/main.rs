@@ -2,22 +2,27 @@ use std::fs::File;
 use std::io::BufReader;
 use std::io::BufRead;
 
-type Lookup = Vec<bool>;
+#[path = "../../common/animate.rs"]
+mod animate;
+
+use animate::Animation;
+
+pub type Lookup = Vec<bool>;
 
 #[derive(Debug, Clone)]
-struct Matrix {
-    data: Vec<bool>,
-    rows: usize,
-    cols: usize,
+pub struct Matrix {
+    pub data: Vec<bool>,
+    pub rows: usize,
+    pub cols: usize,
 }
 
 #[derive(Debug)]
-struct Input {
-    matrix: Matrix,
-    lookup: Lookup,
+pub struct Input {
+    pub matrix: Matrix,
+    pub lookup: Lookup,
 }
 
-fn convert_line_to_bool_vec(line: &String) -> Vec<bool> {
+fn convert_line_to_bool_vec(line: &str) -> Vec<bool> {
     line.trim().chars().map(|char| match char {
         '.' => false,
         '#' => true,
@@ -25,8 +30,8 @@ fn convert_line_to_bool_vec(line: &String) -> Vec<bool> {
     }).collect::<Vec<bool>>()
 }
 
-fn load_from_file(file_path: &str) -> Input {
-    let file = File::open(file_path).expect(std::format!("File not found: {:}", file_path).as_str());
+pub fn load_from_file(file_path: &str) -> Input {
+    let file = File::open(file_path).unwrap_or_else(|_| panic!("File not found: {:}", file_path));
     let mut reader = BufReader::new(file);
     let mut buf = String::new();
 
@@ -38,11 +43,14 @@ fn load_from_file(file_path: &str) -> Input {
     let mut data = Vec::new();
     let mut line_count = 0;
     for line_res in reader.lines() {
-        if let Ok(line) = line_res {
-            let mut values = convert_line_to_bool_vec(&line);
-            data.append(&mut values);
-            line_count += 1;
-        }
+        // unreadable lines are skipped
+        let line = match line_res {
+            Ok(line) => line,
+            Err(_) => continue,
+        };
+        let mut values = convert_line_to_bool_vec(&line);
+        data.append(&mut values);
+        line_count += 1;
     }
 
     Input {
@@ -51,7 +59,7 @@ fn load_from_file(file_path: &str) -> Input {
     }
 }
 
-fn get_pixel_index(mat: &Matrix, r: i64, c: i64, oob_val: usize) -> usize {
+pub fn get_pixel_index(mat: &Matrix, r: i64, c: i64, oob_val: usize) -> usize {
     let mut val = 0;
     for rr in -1..=1 {
         for cc in -1..=1 {
@@ -74,21 +82,21 @@ fn get_pixel_index(mat: &Matrix, r: i64, c: i64, oob_val: usize) -> usize {
     val
 }
 
-fn step(i_mat: &Matrix, o_mat: &mut Matrix, lookup: &Lookup, oob_val: usize) {
+pub fn step(i_mat: &Matrix, o_mat: &mut Matrix, lookup: &Lookup, oob_val: usize) {
     for r in 0..o_mat.rows as i64 {
         for c in 0..o_mat.cols as i64 {
             let rr = r - 1;
             let cc = c - 1;
-            o_mat.data[r as usize * o_mat.rows + c as usize] = lookup[get_pixel_index(&i_mat, rr, cc, oob_val)];
+            o_mat.data[r as usize * o_mat.rows + c as usize] = lookup[get_pixel_index(i_mat, rr, cc, oob_val)];
         }
     }
 }
 
-fn count_lit_values(mat: &Matrix) -> usize {
+pub fn count_lit_values(mat: &Matrix) -> usize {
     mat.data.iter().filter(|val| **val).count()
 }
 
-fn count_enhanced_lit_pixels(input: &Input) -> usize {
+fn count_enhanced_lit_pixels(input: &Input, mut on_step: impl FnMut(&Matrix, usize)) -> usize {
     let mut i_mat = input.matrix.clone();
     let mut o_mat = input.matrix.clone();
     o_mat.rows += 2;
@@ -99,11 +107,13 @@ fn count_enhanced_lit_pixels(input: &Input) -> usize {
     let oob_vals = [input.lookup[0] as usize, input.lookup[511] as usize];
     let mut oob_idx = 0;
     
-    for _ in 0..50 {
+    on_step(&i_mat, 0);
+    for step_idx in 1..=50 {
         step(&i_mat, &mut o_mat, &input.lookup, oob_idx);
         
         oob_idx = oob_vals[oob_idx];
         std::mem::swap(&mut i_mat, &mut o_mat);
+        on_step(&i_mat, step_idx);
         
         o_mat.rows += 4;
         o_mat.cols += 4;
@@ -115,8 +125,25 @@ fn count_enhanced_lit_pixels(input: &Input) -> usize {
 }
 
 
+fn render_matrix(mat: &Matrix) -> String {
+    let mut res = String::with_capacity((mat.cols + 1) * mat.rows);
+    for row in mat.data.chunks(mat.cols) {
+        res.extend(row.iter().map(|&lit| if lit { '#' } else { '.' }));
+        res.push('\n');
+    }
+    res
+}
+
 fn main() {
+    let args: Vec<String> = std::env::args().collect();
     let input = load_from_file("data.in");
-    println!("{:}", count_enhanced_lit_pixels(&input));
+
+    let count = match Animation::from_args(&args) {
+        Some(animation) => count_enhanced_lit_pixels(&input, |mat, step| {
+            animation.show(&std::format!("step {:}", step), &render_matrix(mat));
+        }),
+        None => count_enhanced_lit_pixels(&input, |_, _| ()),
+    };
+    println!("{:}", count);
 } 
 
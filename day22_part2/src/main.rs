@@ -4,6 +4,10 @@ use std::io::BufRead;
 
 use regex::Regex;
 
+#[cfg(feature = "alloc-stats")]
+#[path = "../../common/alloc_stats.rs"]
+mod alloc_stats;
+
 #[derive(Debug, Clone, Copy)]
 struct Cuboid {
     x1: i64,
@@ -115,5 +119,8 @@ fn get_lit_cubes(cuboids: &Vec<Cuboid>) -> i64 {
 fn main() {
     let cuboids = load_from_file("data.in");
     println!("{:}", get_lit_cubes(&cuboids));
+
+    #[cfg(feature = "alloc-stats")]
+    alloc_stats::report();
 } 
 
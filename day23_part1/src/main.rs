@@ -6,6 +6,10 @@ use std::io::BufRead;
 
 use regex::Regex;
 
+#[cfg(feature = "alloc-stats")]
+#[path = "../../common/alloc_stats.rs"]
+mod alloc_stats;
+
 #[derive(Debug, Hash, Eq, PartialEq, Clone)]
 enum Char {
     A,
@@ -50,7 +54,7 @@ struct BoardMove {
 }
 
 #[derive(Debug, Hash, Eq, PartialEq, Clone)]
-struct CharState {
+pub struct CharState {
     x: i64,
     y: i64,
     c: Char,
@@ -154,7 +158,7 @@ impl PartialOrd for BoardState {
     }
 }
 
-fn load_from_file(file_path: &str) -> Vec<CharState> {
+pub fn load_from_file(file_path: &str) -> Vec<CharState> {
     let file = File::open(file_path).expect(std::format!("File not found: {:}", file_path).as_str());
     let mut reader = BufReader::new(file);
     let mut buf = String::new();
@@ -332,5 +336,8 @@ fn organize_amphipods(char_states: &Vec<CharState>) -> Option<i64> {
 fn main() {
     let char_states = load_from_file("data.in");
     println!("{:}", organize_amphipods(&char_states).unwrap());
+
+    #[cfg(feature = "alloc-stats")]
+    alloc_stats::report();
 } 
 
@@ -2,6 +2,11 @@ use std::fs::File;
 use std::io::BufReader;
 use std::io::BufRead;
 
+#[path = "../../common/animate.rs"]
+mod animate;
+
+use animate::Animation;
+
 #[derive(Debug, Clone)]
 struct Matrix {
     data: Vec<i32>,
@@ -10,30 +15,33 @@ struct Matrix {
 }
 
 fn load_from_file(file_path: &str) -> Matrix {
-    let file = File::open(file_path).expect(std::format!("File not found: {:}", file_path).as_str());
+    let file = File::open(file_path).unwrap_or_else(|_| panic!("File not found: {:}", file_path));
     let reader = BufReader::new(file);
     
     
     let mut res = Vec::new();
     let mut cols = 0usize;
     for line_res in reader.lines() {
-        if let Ok(line) = line_res {
-            cols = line.trim().len();
-            for ch in line.trim().chars() {
-                res.push(match ch {
-                    '.' => 0,
-                    '>' => 1,
-                    'v' => 2,
-                    _ => panic!("invalid char: '{:}'", ch),
-                });
-            }
+        // unreadable lines are skipped
+        let line = match line_res {
+            Ok(line) => line,
+            Err(_) => continue,
+        };
+        cols = line.trim().len();
+        for ch in line.trim().chars() {
+            res.push(match ch {
+                '.' => 0,
+                '>' => 1,
+                'v' => 2,
+                _ => panic!("invalid char: '{:}'", ch),
+            });
         }
     }
 
     Matrix { rows: res.len() / cols, cols, data: res }
 }
 
-fn part1(mut matrix: Matrix) -> u64 {
+fn part1(mut matrix: Matrix, mut on_step: impl FnMut(&Matrix, u64)) -> u64 {
     let mut buf = matrix.clone();
     
     let last_c = matrix.cols - 1;
@@ -41,6 +49,7 @@ fn part1(mut matrix: Matrix) -> u64 {
     
     let mut moved = true;
     let mut i = 0u64;
+    on_step(&matrix, i);
     while moved {
         moved = false;
         
@@ -87,13 +96,37 @@ fn part1(mut matrix: Matrix) -> u64 {
         }
         
         i += 1;
+        on_step(&matrix, i);
     }
 
     i
 }
 
+fn render_matrix(matrix: &Matrix) -> String {
+    let mut res = String::with_capacity((matrix.cols + 1) * matrix.rows);
+    for r in 0..matrix.rows {
+        for c in 0..matrix.cols {
+            res.push(match matrix.data[r * matrix.cols + c] {
+                1 => '>',
+                2 => 'v',
+                _ => '.',
+            });
+        }
+        res.push('\n');
+    }
+    res
+}
+
 fn main() {
+    let args: Vec<String> = std::env::args().collect();
     let matrix = load_from_file("data.in");
-    println!("{:}", part1(matrix));
+
+    let res = match Animation::from_args(&args) {
+        Some(animation) => part1(matrix, |matrix, step| {
+            animation.show(&std::format!("step {:}", step), &render_matrix(matrix));
+        }),
+        None => part1(matrix, |_, _| ()),
+    };
+    println!("{:}", res);
 } 
 